@@ -28,6 +28,12 @@ pub(crate) enum ShellCommand {
         #[command(subcommand)]
         cmd: PortForwardsCommand,
     },
+
+    /// Manage wireless networks
+    Wifi {
+        #[command(subcommand)]
+        cmd: WifiCommand,
+    },
 }
 
 #[derive(Parser, Debug)]
@@ -58,6 +64,47 @@ pub(crate) enum PortForwardsCommand {
     },
 }
 
+#[derive(Parser, Debug)]
+pub(crate) enum WifiCommand {
+    /// Show the settings of both radios
+    Show,
+    /// Scan for neighbouring networks and recommend the least congested channel
+    Optimize {
+        /// Band to optimize, either 2.4 or 5. If unspecified, both bands are optimized
+        band: Option<String>,
+        /// Switch to the recommended channel
+        #[arg(short, long)]
+        apply: bool,
+    },
+    /// Manage WPS
+    Wps {
+        #[command(subcommand)]
+        cmd: WpsCommand,
+    },
+}
+
+#[derive(Parser, Debug)]
+pub(crate) enum WpsCommand {
+    /// Show the WPS status
+    Status,
+    /// Enable WPS
+    Enable,
+    /// Disable WPS
+    Disable,
+    /// Start a push-button session
+    Pbc {
+        /// Band to start the session on, either 2.4 or 5
+        band: String,
+    },
+    /// Start a PIN session
+    Pin {
+        /// Band to start the session on, either 2.4 or 5
+        band: String,
+        /// PIN of the client device
+        pin: String,
+    },
+}
+
 pub(crate) fn shell_cmd() -> Command {
     ShellCommand::augment_subcommands(Command::new("").multicall(true))
 }
@@ -1 +1,2 @@
 pub mod pfw;
+pub mod wifi;
@@ -0,0 +1,158 @@
+use ascii_table::{Align::Right, AsciiTable};
+use color_eyre::Result;
+use color_print::{cprint, cprintln};
+use connectbox::models::{RadioSettings, WifiBand};
+use once_cell::sync::OnceCell;
+
+use crate::{
+    cli::{WifiCommand, WpsCommand},
+    AppState,
+};
+
+static RADIO_TABLE: OnceCell<AsciiTable> = OnceCell::new();
+
+fn init_radio_table() -> AsciiTable {
+    let mut t = AsciiTable::default();
+    t.column(0).set_header("Band");
+    t.column(1).set_header("Enabled");
+    t.column(2).set_header("SSID");
+    t.column(3).set_header("Broadcast");
+    t.column(4).set_header("Security");
+    t.column(5).set_header("Channel");
+    t.column(6).set_header("Width");
+    t.column(7).set_header("Mode");
+    t.column(8).set_header("Power");
+    t
+}
+
+static SCORE_TABLE: OnceCell<AsciiTable> = OnceCell::new();
+
+fn init_score_table() -> AsciiTable {
+    let mut t = AsciiTable::default();
+    t.column(0).set_header("Channel").set_align(Right);
+    t.column(1).set_header("Interference").set_align(Right);
+    t.column(2).set_header("Co-channel APs").set_align(Right);
+    t.column(3).set_header("Adjacent APs").set_align(Right);
+    t
+}
+
+fn radio_row(band: WifiBand, r: &RadioSettings) -> Vec<String> {
+    let channel = match r.channel {
+        Some(channel) => channel.to_string(),
+        None => format!("auto ({})", r.current_channel),
+    };
+    vec![
+        band.to_string(),
+        r.enabled.to_string(),
+        r.ssid.clone(),
+        r.broadcast_ssid.to_string(),
+        r.security.to_string(),
+        channel,
+        r.channel_width.to_string(),
+        r.mode.to_string(),
+        r.transmit_power.to_string(),
+    ]
+}
+
+pub(crate) async fn run(cmd: WifiCommand, state: &AppState) -> Result<()> {
+    match cmd {
+        WifiCommand::Show => {
+            cprintln!("<blue!>Retrieving the wireless settings...");
+            let settings = state.connect_box.wireless_settings().await?;
+            let rows = [WifiBand::Band2_4GHz, WifiBand::Band5GHz]
+                .map(|band| radio_row(band, settings.band(band)));
+            let rendered_table = RADIO_TABLE.get_or_init(init_radio_table).format(rows);
+            cprint!("{rendered_table}");
+        }
+        WifiCommand::Optimize { band, apply } => {
+            let bands = match band {
+                Some(band) => {
+                    let Some(band) = WifiBand::new(&band) else {
+                        cprintln!("<red!>Invalid band {band:?}");
+                        return Ok(());
+                    };
+                    vec![band]
+                }
+                None => vec![WifiBand::Band2_4GHz, WifiBand::Band5GHz],
+            };
+            for band in bands {
+                cprintln!("<blue!>Scanning the {band} band...");
+                let rec = state.connect_box.optimize_wifi_channel(band, apply).await?;
+                let scores = rec.scores.iter().take(5).map(|s| {
+                    vec![
+                        s.channel.to_string(),
+                        format!("{:.1}", s.score),
+                        s.co_channel.to_string(),
+                        s.adjacent_channel.to_string(),
+                    ]
+                });
+                let rendered_table = SCORE_TABLE.get_or_init(init_score_table).format(scores);
+                cprint!("{rendered_table}");
+                if !rec.is_change() {
+                    cprintln!(
+                        "<green!>Channel {} is already the best choice",
+                        rec.current_channel
+                    );
+                } else if apply {
+                    cprintln!(
+                        "<green!>Switched from channel {} to {}",
+                        rec.current_channel,
+                        rec.recommended_channel
+                    );
+                } else {
+                    cprintln!(
+                        "<yellow!>Channel {} is recommended over the current channel {}, use -a to apply",
+                        rec.recommended_channel,
+                        rec.current_channel
+                    );
+                }
+            }
+        }
+        WifiCommand::Wps { cmd } => run_wps(cmd, state).await?,
+    }
+    Ok(())
+}
+
+async fn run_wps(cmd: WpsCommand, state: &AppState) -> Result<()> {
+    match cmd {
+        WpsCommand::Status => {
+            let status = state.connect_box.wps_status().await?;
+            cprintln!(
+                "Enabled: {}\nLast session: {}\nRouter PIN: {}",
+                status.enabled,
+                status.state,
+                status.router_pin
+            );
+        }
+        WpsCommand::Enable => {
+            cprintln!("<blue!>Enabling WPS...");
+            state.connect_box.set_wps_enabled(true).await?;
+            cprintln!("<green!>Done!");
+        }
+        WpsCommand::Disable => {
+            cprintln!("<blue!>Disabling WPS...");
+            state.connect_box.set_wps_enabled(false).await?;
+            cprintln!("<green!>Done!");
+        }
+        WpsCommand::Pbc { band } => {
+            let Some(band) = WifiBand::new(&band) else {
+                cprintln!("<red!>Invalid band {band:?}");
+                return Ok(());
+            };
+            state.connect_box.start_wps_push_button(band).await?;
+            cprintln!("<green!>WPS push-button session started on the {band} band");
+        }
+        WpsCommand::Pin { band, pin } => {
+            let Some(band) = WifiBand::new(&band) else {
+                cprintln!("<red!>Invalid band {band:?}");
+                return Ok(());
+            };
+            match state.connect_box.start_wps_pin(band, &pin).await {
+                Ok(()) => cprintln!("<green!>WPS PIN session started on the {band} band"),
+                Err(e @ connectbox::Error::InvalidWpsPin(_)) => cprintln!("<red!>{e}"),
+                Err(e) => return Err(e.into()),
+            }
+        }
+    }
+    Ok(())
+}
@@ -101,6 +101,7 @@ async fn main() -> Result<()> {
                 match cmd {
                     ShellCommand::Exit => break,
                     ShellCommand::PortForwards { cmd } => commands::pfw::run(cmd, &state).await?,
+                    ShellCommand::Wifi { cmd } => commands::wifi::run(cmd, &state).await?,
                 }
             }
             Err(ReadlineError::Interrupted | ReadlineError::Eof) => break,
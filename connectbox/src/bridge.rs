@@ -0,0 +1,94 @@
+use std::{fmt::Display, sync::Arc};
+
+use reqwest::{cookie::Jar, Client, Url};
+use serde::de::DeserializeOwned;
+
+use crate::{find_cookie, http_client, models, status, Error, Field, Result};
+
+/// A client for the limited status interface that is available when the modem is in [bridge mode](models::OperatingMode::Bridge).
+///
+/// Unlike [`ConnectBox`](crate::ConnectBox), it doesn't log in, so it doesn't need a password and doesn't block other users from accessing the web interface.
+/// Only the DOCSIS and system status can be retrieved through it.
+pub struct BridgeModeClient {
+    http: Client,
+    cookie_store: Arc<Jar>,
+    base_url: Url,
+    getter_url: Url,
+}
+
+impl BridgeModeClient {
+    /// Create a new client associated with the specified address. This will usually be [`BRIDGE_MODE_ADDRESS`](crate::BRIDGE_MODE_ADDRESS).
+    pub fn new(address: impl Display) -> Result<Self> {
+        let cookie_store = Arc::new(Jar::default());
+        let http = http_client(&cookie_store)?;
+        let base_url: Url = format!("http://{address}/").parse()?;
+        let getter_url = base_url.join("xml/getter.xml")?;
+        Ok(BridgeModeClient {
+            http,
+            cookie_store,
+            base_url,
+            getter_url,
+        })
+    }
+
+    async fn refresh_session_token(&self) -> Result<()> {
+        self.http
+            .get(self.base_url.join("common_page/login.html")?)
+            .send()
+            .await?;
+        Ok(())
+    }
+
+    async fn xml_getter<T: DeserializeOwned>(&self, function: u32) -> Result<T> {
+        let mut refreshed = false;
+        loop {
+            let session_token =
+                match find_cookie(&self.cookie_store, &self.base_url, "sessionToken")? {
+                    Some(token) => token,
+                    None if !refreshed => {
+                        refreshed = true;
+                        self.refresh_session_token().await?;
+                        continue;
+                    }
+                    None => return Err(Error::NoSessionToken),
+                };
+            let form: &[Field] = &[
+                ("token".into(), session_token.into()),
+                ("fun".into(), function.to_string().into()),
+            ];
+            tracing::debug!("Executing getter {function} in bridge mode");
+            let req = self.http.post(self.getter_url.clone()).form(form);
+            let resp = req.send().await?;
+            if resp.status().is_redirection() {
+                if !refreshed {
+                    refreshed = true;
+                    self.refresh_session_token().await?;
+                    continue;
+                }
+                return Err(Error::NotAuthorized);
+            }
+            return Ok(quick_xml::de::from_str(&resp.text().await?)?);
+        }
+    }
+
+    /// Get the status of all downstream channels the modem is locked to.
+    pub async fn downstream_channels(&self) -> Result<models::DownstreamChannels> {
+        status::downstream_channels(self).await
+    }
+
+    /// Get the status of all upstream channels the modem is transmitting on.
+    pub async fn upstream_channels(&self) -> Result<models::UpstreamChannels> {
+        status::upstream_channels(self).await
+    }
+
+    /// Get the modem's system information, such as the firmware version, uptime and provisioning status.
+    pub async fn system_info(&self) -> Result<models::SystemInfo> {
+        status::system_info(self).await
+    }
+}
+
+impl status::XmlGetter for BridgeModeClient {
+    async fn xml_getter<T: DeserializeOwned>(&self, function: u32) -> Result<T> {
+        BridgeModeClient::xml_getter(self, function).await
+    }
+}
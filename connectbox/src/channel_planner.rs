@@ -0,0 +1,235 @@
+//! Wi-Fi channel recommendations based on a scan of neighbouring access points.
+//!
+//! Every channel of a band is scored by how much interference it would get from the neighbouring networks.
+//! Access points on the same channel (co-channel) and on overlapping channels (adjacent-channel) both count,
+//! weighted by their signal strength. Adjacent-channel interference is weighted higher, because unlike co-channel
+//! networks, overlapping networks can't coordinate their transmissions with ours and show up as plain noise.
+//!
+//! DFS channels (52–144 in the 5 GHz band) are never recommended: switching to one of them makes the radio listen
+//! for radar for at least a minute before it can transmit, which takes the network offline.
+
+use crate::models::{AccessPoint, ChannelWidth, RadioSettings, WifiBand};
+
+/// How much more adjacent-channel interference counts compared to co-channel interference
+const ADJACENT_CHANNEL_PENALTY: f64 = 1.5;
+/// Signal strength at which an access point stops contributing to the interference, in dBm
+const NOISE_FLOOR: i32 = -95;
+
+/// Interference score of a single channel
+#[derive(Debug, Clone)]
+pub struct ChannelScore {
+    pub channel: u8,
+    /// Total interference, lower is better
+    pub score: f64,
+    /// Number of access points on this exact channel
+    pub co_channel: usize,
+    /// Number of access points on channels overlapping with this one
+    pub adjacent_channel: usize,
+}
+
+/// Result of [`recommend_channel`]
+#[derive(Debug, Clone)]
+pub struct ChannelRecommendation {
+    pub band: WifiBand,
+    /// Channel the radio is currently operating on
+    pub current_channel: u8,
+    /// Channel with the least interference
+    pub recommended_channel: u8,
+    /// Scores of all non-DFS channels in the band, ordered from best to worst
+    pub scores: Vec<ChannelScore>,
+}
+
+impl ChannelRecommendation {
+    /// Whether switching to the recommended channel would change anything
+    #[must_use]
+    pub fn is_change(&self) -> bool {
+        self.current_channel != self.recommended_channel
+    }
+}
+
+/// Score every channel of `band` based on the `neighbours` found by [`ConnectBox::wifi_scan`](crate::ConnectBox::wifi_scan) and recommend the best one for `radio`.
+///
+/// When several channels are equally good, the current channel is preferred to avoid needlessly disconnecting clients.
+/// DFS channels are not considered, so if the radio is currently on one, a different channel is always recommended.
+#[must_use]
+pub fn recommend_channel(
+    band: WifiBand,
+    radio: &RadioSettings,
+    neighbours: &[AccessPoint],
+) -> ChannelRecommendation {
+    let span = channel_span(band, radio.channel_width);
+    let mut scores: Vec<ChannelScore> = band
+        .channels()
+        .iter()
+        .filter(|&&channel| !is_dfs(channel))
+        .map(|&channel| score_channel(channel, span, neighbours))
+        .collect();
+    scores.sort_by(|a, b| {
+        a.score
+            .total_cmp(&b.score)
+            .then_with(|| {
+                (b.channel == radio.current_channel).cmp(&(a.channel == radio.current_channel))
+            })
+            .then_with(|| a.channel.cmp(&b.channel))
+    });
+    ChannelRecommendation {
+        band,
+        current_channel: radio.current_channel,
+        recommended_channel: scores[0].channel,
+        scores,
+    }
+}
+
+/// Whether the channel requires dynamic frequency selection, i.e. a radar check before it can be used
+fn is_dfs(channel: u8) -> bool {
+    (52..=144).contains(&channel)
+}
+
+/// Distance in channel numbers below which two networks overlap
+fn channel_span(band: WifiBand, width: ChannelWidth) -> u8 {
+    match (band, width) {
+        // 2.4 GHz channels are 5 MHz apart, but a 20 MHz wide signal covers 5 of them
+        (WifiBand::Band2_4GHz, ChannelWidth::Mhz20) => 5,
+        (WifiBand::Band2_4GHz, _) => 9,
+        (WifiBand::Band5GHz, ChannelWidth::Mhz20) => 4,
+        (WifiBand::Band5GHz, ChannelWidth::Mhz40) => 8,
+        (WifiBand::Band5GHz, ChannelWidth::Mhz80) => 16,
+    }
+}
+
+fn score_channel(channel: u8, span: u8, neighbours: &[AccessPoint]) -> ChannelScore {
+    let mut score = ChannelScore {
+        channel,
+        score: 0.0,
+        co_channel: 0,
+        adjacent_channel: 0,
+    };
+    for ap in neighbours {
+        let strength = f64::from((ap.signal - NOISE_FLOOR).max(0));
+        let distance = ap.channel.abs_diff(channel);
+        if distance == 0 {
+            score.co_channel += 1;
+            score.score += strength;
+        } else if distance < span {
+            let overlap = f64::from(span - distance) / f64::from(span);
+            score.adjacent_channel += 1;
+            score.score += strength * overlap * ADJACENT_CHANNEL_PENALTY;
+        }
+    }
+    score
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::models::{AccessPointSecurity, MacAddress, TransmitPower, WifiMode, WifiSecurity};
+
+    fn radio(current_channel: u8, channel_width: ChannelWidth) -> RadioSettings {
+        RadioSettings {
+            enabled: true,
+            ssid: "test".to_string(),
+            broadcast_ssid: true,
+            security: WifiSecurity::Wpa2Psk,
+            passphrase: "password".to_string(),
+            channel: None,
+            current_channel,
+            channel_width,
+            mode: WifiMode::N,
+            transmit_power: TransmitPower::Percent100,
+        }
+    }
+
+    fn ap(channel: u8, signal: i32) -> AccessPoint {
+        AccessPoint {
+            ssid: "neighbour".to_string(),
+            bssid: MacAddress([0; 6]),
+            channel,
+            signal,
+            security: AccessPointSecurity::Wpa2,
+        }
+    }
+
+    fn score(rec: &ChannelRecommendation, channel: u8) -> &ChannelScore {
+        rec.scores.iter().find(|s| s.channel == channel).unwrap()
+    }
+
+    #[test]
+    fn adjacent_channel_scores_worse_than_co_channel() {
+        let neighbours = [ap(6, -45)];
+        let rec = recommend_channel(
+            WifiBand::Band2_4GHz,
+            &radio(6, ChannelWidth::Mhz20),
+            &neighbours,
+        );
+        let (co, adjacent) = (score(&rec, 6), score(&rec, 5));
+        assert_eq!((co.co_channel, co.adjacent_channel), (1, 0));
+        assert_eq!((adjacent.co_channel, adjacent.adjacent_channel), (0, 1));
+        assert!(adjacent.score > co.score);
+        // channel 1 is 5 channels away, so it doesn't overlap with 20 MHz channels
+        assert_eq!(score(&rec, 1).score, 0.0);
+        assert_eq!(rec.recommended_channel, 1);
+        assert!(rec.is_change());
+    }
+
+    #[test]
+    fn weak_neighbours_are_ignored() {
+        let neighbours = [ap(6, NOISE_FLOOR - 5)];
+        let rec = recommend_channel(
+            WifiBand::Band2_4GHz,
+            &radio(6, ChannelWidth::Mhz20),
+            &neighbours,
+        );
+        assert_eq!(score(&rec, 6).score, 0.0);
+        assert!(!rec.is_change());
+    }
+
+    #[test]
+    fn ties_keep_the_current_channel() {
+        let neighbours = [ap(6, -60)];
+        let rec = recommend_channel(
+            WifiBand::Band2_4GHz,
+            &radio(11, ChannelWidth::Mhz20),
+            &neighbours,
+        );
+        assert_eq!(score(&rec, 1).score, score(&rec, 11).score);
+        assert_eq!(rec.recommended_channel, 11);
+        assert!(!rec.is_change());
+
+        let rec = recommend_channel(WifiBand::Band2_4GHz, &radio(4, ChannelWidth::Mhz20), &[]);
+        assert_eq!(rec.recommended_channel, 4);
+    }
+
+    #[test]
+    fn wider_channels_overlap_more() {
+        let neighbours = [ap(36, -50)];
+        let adjacent = |width| {
+            let rec = recommend_channel(WifiBand::Band5GHz, &radio(36, width), &neighbours);
+            [40, 44, 48].map(|channel| score(&rec, channel).adjacent_channel)
+        };
+        assert_eq!(adjacent(ChannelWidth::Mhz20), [0, 0, 0]);
+        assert_eq!(adjacent(ChannelWidth::Mhz40), [1, 0, 0]);
+        assert_eq!(adjacent(ChannelWidth::Mhz80), [1, 1, 1]);
+
+        let neighbours = [ap(1, -50)];
+        let rec = recommend_channel(
+            WifiBand::Band2_4GHz,
+            &radio(1, ChannelWidth::Mhz40),
+            &neighbours,
+        );
+        assert_eq!(score(&rec, 9).adjacent_channel, 1);
+        assert_eq!(score(&rec, 10).adjacent_channel, 0);
+    }
+
+    #[test]
+    fn dfs_channels_are_never_recommended() {
+        let neighbours = [ap(36, -40), ap(40, -40), ap(44, -40), ap(48, -40)];
+        let rec = recommend_channel(
+            WifiBand::Band5GHz,
+            &radio(100, ChannelWidth::Mhz20),
+            &neighbours,
+        );
+        assert!(rec.scores.iter().all(|s| !is_dfs(s.channel)));
+        assert!([36, 40, 44, 48].contains(&rec.recommended_channel));
+        assert!(rec.is_change());
+    }
+}
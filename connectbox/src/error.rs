@@ -1,5 +1,9 @@
+use std::net::Ipv4Addr;
+
 use thiserror::Error;
 
+use crate::models::WifiBand;
+
 /// The error type used globally by the library
 #[derive(Error, Debug)]
 pub enum Error {
@@ -17,6 +21,42 @@ pub enum Error {
     UnexpectedRedirect(String),
     #[error("remote error: {0:?}")]
     Remote(String),
+    #[error("the router did not finish the {0} in time")]
+    Timeout(&'static str),
+    #[error("invalid MAC address: {0:?}")]
+    InvalidMacAddress(String),
+    #[error("invalid IPv6 prefix: {0:?}")]
+    InvalidIpv6Prefix(String),
+    #[error("invalid SSID {0:?}, it must be between 1 and 32 bytes long")]
+    InvalidSsid(String),
+    #[error("invalid passphrase, it must be 8 to 63 printable ASCII characters or 64 hexadecimal digits")]
+    InvalidPassphrase,
+    #[error("invalid WPS PIN {0:?}, it must be 4 digits or 8 digits with a valid checksum")]
+    InvalidWpsPin(String),
+    #[error("invalid subnet mask {0}")]
+    InvalidSubnetMask(Ipv4Addr),
+    #[error("address {0} is not a usable host address in the LAN subnet")]
+    AddressOutsideSubnet(Ipv4Addr),
+    #[error("invalid DHCP pool {0}-{1}")]
+    InvalidDhcpPool(Ipv4Addr, Ipv4Addr),
+    #[error("invalid address range {0}-{1}")]
+    InvalidAddressRange(Ipv4Addr, Ipv4Addr),
+    #[error("invalid port range {0}-{1}")]
+    InvalidPortRange(u16, u16),
+    #[error("invalid IPv6 LAN settings: {0}")]
+    InvalidIpv6LanSettings(&'static str),
+    #[error("invalid schedule: {0}")]
+    InvalidSchedule(&'static str),
+    #[error("invalid blocked keyword or URL {0:?}")]
+    InvalidKeyword(String),
+    #[error("invalid diagnostic target {0:?}, it must be a hostname or an IP address")]
+    InvalidDiagnosticTarget(String),
+    #[error("the number of pings must be at least 1")]
+    InvalidPingCount,
+    #[error("channel {channel} is not available in the {band} band")]
+    InvalidChannel { band: WifiBand, channel: u8 },
+    #[error("{setting} is not supported in the {band} band")]
+    UnsupportedRadioSetting { band: WifiBand, setting: String },
 
     #[error(transparent)]
     URLParseError(#[from] url::ParseError),
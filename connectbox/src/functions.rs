@@ -1,5 +1,26 @@
 // Setters
 
+pub const REBOOT: u32 = 8;
+pub const FACTORY_RESET: u32 = 7;
+pub const EDIT_OPERATING_MODE: u32 = 160;
+pub const EDIT_WIRELESS_BASIC: u32 = 301;
+pub const EDIT_GUEST_NETWORK: u32 = 308;
+pub const EDIT_WPS: u32 = 303;
+pub const START_WPS: u32 = 304;
+pub const START_WIFI_SCAN: u32 = 325;
+pub const EDIT_LAN_SETTINGS: u32 = 101;
+pub const EDIT_DHCP_RESERVATIONS: u32 = 106;
+pub const EDIT_IP_FILTERS: u32 = 111;
+pub const EDIT_MAC_FILTERS: u32 = 113;
+pub const EDIT_PARENTAL_CONTROL: u32 = 151;
+pub const EDIT_ACCESS_SCHEDULES: u32 = 152;
+pub const EDIT_CONTENT_FILTER: u32 = 153;
+pub const EDIT_FIREWALL: u32 = 117;
+pub const EDIT_DMZ: u32 = 119;
+pub const EDIT_UPNP: u32 = 132;
+pub const START_PING: u32 = 126;
+pub const START_TRACEROUTE: u32 = 128;
+pub const EDIT_IPV6_LAN_SETTINGS: u32 = 149;
 pub const LOGIN: u32 = 15;
 pub const LOGOUT: u32 = 16;
 pub const EDIT_FORWARDS: u32 = 122;
@@ -8,3 +29,25 @@ pub const EDIT_FORWARDS: u32 = 122;
 
 pub const LAN_TABLE: u32 = 123;
 pub const FORWARDS: u32 = 121;
+pub const DOWNSTREAM_TABLE: u32 = 10;
+pub const UPSTREAM_TABLE: u32 = 11;
+pub const GLOBAL_SETTINGS: u32 = 1;
+pub const CM_SYSTEM_INFO: u32 = 2;
+pub const CM_STATUS: u32 = 144;
+pub const WIRELESS_BASIC: u32 = 300;
+pub const GUEST_NETWORK: u32 = 307;
+pub const WPS: u32 = 302;
+pub const WIFI_SCAN: u32 = 326;
+pub const LAN_SETTINGS: u32 = 100;
+pub const DHCP_RESERVATIONS: u32 = 105;
+pub const IP_FILTERS: u32 = 110;
+pub const MAC_FILTERS: u32 = 112;
+pub const PARENTAL_CONTROL: u32 = 150;
+pub const FIREWALL: u32 = 116;
+pub const DMZ: u32 = 118;
+pub const UPNP: u32 = 131;
+pub const PING_RESULT: u32 = 127;
+pub const TRACEROUTE_RESULT: u32 = 129;
+pub const WAN_STATUS: u32 = 146;
+pub const IPV6_WAN_STATUS: u32 = 147;
+pub const IPV6_LAN_SETTINGS: u32 = 148;
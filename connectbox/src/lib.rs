@@ -1,10 +1,17 @@
 //! API client library for the Compal CH7465LG, which is a cable modem provided by various European ISPs under the name Connect Box.
 
 #![allow(clippy::missing_errors_doc)]
-use std::{borrow::Cow, fmt::Display, sync::Arc};
+use std::{borrow::Cow, fmt::Display, net::Ipv4Addr, sync::Arc, time::Duration};
 
+pub use bridge::BridgeModeClient;
+use channel_planner::ChannelRecommendation;
 pub use error::Error;
-use models::PortForwardEntry;
+use models::{
+    AccessPoint, AccessSchedule, DhcpReservation, FirewallSettings, GuestNetwork, IpFilterEntry,
+    Ipv6LanSettings, LanSettings, MacAddress, MacFilterEntry, OperatingMode, PingResult,
+    PortForwardEntry, RadioSettings, RadioTuning, TracerouteHop, WifiBand, WifiSecurity,
+    WirelessSettings,
+};
 use reqwest::{
     cookie::{CookieStore, Jar},
     header::HeaderValue,
@@ -12,17 +19,30 @@ use reqwest::{
     Client, Url,
 };
 use serde::de::DeserializeOwned;
+use tokio::time::sleep;
 
+mod bridge;
+pub mod channel_planner;
 mod error;
 mod functions;
 /// Data structures used by the library
 pub mod models;
+mod status;
+mod validation;
 
 /// A Result type based on the library's Error
 pub type Result<T> = std::result::Result<T, error::Error>;
 
 type Field<'a, 'b> = (Cow<'a, str>, Cow<'b, str>);
 
+/// Address of the web interface when the modem is in [bridge mode](models::OperatingMode::Bridge)
+pub const BRIDGE_MODE_ADDRESS: Ipv4Addr = Ipv4Addr::new(192, 168, 100, 1);
+
+const REBOOT_POLL_INTERVAL: Duration = Duration::from_secs(5);
+const POLL_INTERVAL: Duration = Duration::from_secs(2);
+const WIFI_SCAN_TIMEOUT: Duration = Duration::from_secs(30);
+const TRACEROUTE_TIMEOUT: Duration = Duration::from_secs(180);
+
 /// The entry point of the library - the API client
 pub struct ConnectBox {
     http: Client,
@@ -40,11 +60,7 @@ impl ConnectBox {
     /// * `auto_reauth` - whether to automatically re-authenticate when the session expires
     pub fn new(address: impl Display, password: String, auto_reauth: bool) -> Result<Self> {
         let cookie_store = Arc::new(Jar::default());
-        let http = Client::builder()
-            .user_agent("Mozilla/5.0")
-            .redirect(Policy::none())
-            .cookie_provider(cookie_store.clone())
-            .build()?;
+        let http = http_client(&cookie_store)?;
         let base_url: Url = format!("http://{address}/").parse()?;
         let getter_url = base_url.join("xml/getter.xml")?;
         let setter_url = base_url.join("xml/setter.xml")?;
@@ -60,18 +76,7 @@ impl ConnectBox {
     }
 
     fn cookie(&self, name: &str) -> Result<Option<String>> {
-        let Some(cookies) = self.cookie_store.cookies(&self.base_url) else {
-            return Ok(None)
-        };
-        let cookies = cookies.to_str()?;
-        let Some(mut cookie_start) = cookies.find(&format!("{name}=")) else {
-            return Ok(None)
-        };
-        cookie_start += name.len() + 1;
-        let cookie_end = cookies[cookie_start..]
-            .find(';')
-            .map_or(cookies.len(), |p| p + cookie_start);
-        Ok(Some(cookies[cookie_start..cookie_end].to_string()))
+        find_cookie(&self.cookie_store, &self.base_url, name)
     }
 
     async fn xml_getter<T: DeserializeOwned>(&self, function: u32) -> Result<T> {
@@ -133,6 +138,32 @@ impl ConnectBox {
         }
     }
 
+    /// Call a getter repeatedly until `done` returns true for its result. Returns `None` if that doesn't happen within `timeout`.
+    async fn poll_getter<T, F>(
+        &self,
+        function: u32,
+        timeout: Duration,
+        done: F,
+    ) -> Result<Option<T>>
+    where
+        T: DeserializeOwned,
+        F: Fn(&T) -> bool,
+    {
+        let poll = async {
+            loop {
+                sleep(POLL_INTERVAL).await;
+                let result = self.xml_getter(function).await?;
+                if done(&result) {
+                    return Ok(result);
+                }
+            }
+        };
+        match tokio::time::timeout(timeout, poll).await {
+            Ok(result) => result.map(Some),
+            Err(_) => Ok(None),
+        }
+    }
+
     async fn _login(&self) -> Result<()> {
         let session_token = self.cookie("sessionToken")?.ok_or(Error::NoSessionToken)?;
         let form: &[Field] = &[
@@ -190,6 +221,155 @@ impl ConnectBox {
         Ok(())
     }
 
+    /// Reboot the modem.
+    ///
+    /// The current session does not survive the reboot, so you will have to [`login`](Self::login()) again once the modem is back online. Use [`reboot_and_wait`](Self::reboot_and_wait()) if you want that handled for you.
+    pub async fn reboot(&self) -> Result<()> {
+        let resp = self.xml_setter(functions::REBOOT, None).await?;
+        if resp.is_empty() {
+            Ok(())
+        } else {
+            Err(Error::Remote(resp))
+        }
+    }
+
+    /// Reboot the modem, wait until its web interface is reachable again and log back in.
+    ///
+    /// Returns [`Error::Timeout`] if the modem does not come back online within `timeout`.
+    pub async fn reboot_and_wait(&self, timeout: Duration) -> Result<()> {
+        self.reboot().await?;
+        let wait = async {
+            // wait for the modem to actually go down first, otherwise we could log in right before it restarts
+            while self.is_reachable().await? {
+                sleep(REBOOT_POLL_INTERVAL).await;
+            }
+            tracing::info!("modem went down, waiting for it to come back online");
+            while !self.is_reachable().await? {
+                sleep(REBOOT_POLL_INTERVAL).await;
+            }
+            Ok::<_, Error>(())
+        };
+        tokio::time::timeout(timeout, wait)
+            .await
+            .map_err(|_| Error::Timeout("reboot"))??;
+        tracing::info!("modem is back online, logging in");
+        self.login().await
+    }
+
+    async fn is_reachable(&self) -> Result<bool> {
+        let resp = self
+            .http
+            .get(self.base_url.join("common_page/login.html")?)
+            .timeout(REBOOT_POLL_INTERVAL)
+            .send()
+            .await;
+        Ok(resp.is_ok())
+    }
+
+    /// Get whether the modem is operating as a router or as a plain modem (bridge mode).
+    pub async fn operating_mode(&self) -> Result<OperatingMode> {
+        let global: models::GlobalOperatingMode =
+            self.xml_getter(functions::GLOBAL_SETTINGS).await?;
+        Ok(global.operating_mode)
+    }
+
+    /// Switch between router mode and bridge mode.
+    ///
+    /// The modem reboots after switching, which ends the current session, and the address of the web interface changes:
+    /// * in bridge mode, it is only reachable at [`BRIDGE_MODE_ADDRESS`] from the device connected to the modem. All router functions (LAN, Wi-Fi, port forwarding, etc.) are unavailable there.
+    ///   Use [`BridgeModeClient`] to monitor the modem's status without logging in.
+    /// * in router mode, it is reachable at the LAN address of the router again (`192.168.0.1` by default).
+    ///
+    /// Once the modem is back online, create a new client with the new address to continue. To leave bridge mode, log in at the bridge mode address and switch back to router mode:
+    ///
+    /// ```no_run
+    /// # async fn example(password: String) -> connectbox::Result<()> {
+    /// use connectbox::{models::OperatingMode, ConnectBox, BRIDGE_MODE_ADDRESS};
+    ///
+    /// let connect_box = ConnectBox::new(BRIDGE_MODE_ADDRESS, password, false)?;
+    /// connect_box.login().await?;
+    /// connect_box.set_operating_mode(OperatingMode::Router).await?;
+    /// # Ok(())
+    /// # }
+    /// ```
+    pub async fn set_operating_mode(&self, mode: OperatingMode) -> Result<()> {
+        let fields = [("GwOperMode".into(), mode.id_str().into())];
+        let resp = self
+            .xml_setter(functions::EDIT_OPERATING_MODE, Some(&fields))
+            .await?;
+        if !resp.is_empty() {
+            return Err(Error::Remote(resp));
+        }
+        tracing::info!(
+            "session <{}>: switched to {mode} mode, the modem is rebooting",
+            self.cookie("SID")?.as_deref().unwrap_or("unknown")
+        );
+        Ok(())
+    }
+
+    /// Restore the modem to its factory settings.
+    ///
+    /// **This erases all settings, including the password and the wireless configuration.** The modem reboots afterwards, and the current session is lost.
+    /// To make sure this can't happen by mistake, a [`FactoryResetConfirmation`] must be passed.
+    pub async fn factory_reset(&self, _confirmation: FactoryResetConfirmation) -> Result<()> {
+        let resp = self.xml_setter(functions::FACTORY_RESET, None).await?;
+        if resp.is_empty() {
+            Ok(())
+        } else {
+            Err(Error::Remote(resp))
+        }
+    }
+
+    /// Ping `target` from the router itself, sending `count` probes, and wait for the results.
+    ///
+    /// `target` can be either a hostname or an IP address.
+    pub async fn ping(&self, target: &str, count: u8) -> Result<PingResult> {
+        validation::diagnostic_target(target)?;
+        validation::ping_count(count)?;
+        let fields = [
+            ("Target".into(), target.into()),
+            ("Count".into(), count.to_string().into()),
+        ];
+        let resp = self
+            .xml_setter(functions::START_PING, Some(&fields))
+            .await?;
+        if !resp.is_empty() {
+            return Err(Error::Remote(resp));
+        }
+        // probes are sent one second apart, and each one can take a few seconds to time out
+        let timeout = Duration::from_secs(10 + 2 * u64::from(count));
+        let status = self
+            .poll_getter(functions::PING_RESULT, timeout, |s: &models::PingStatus| {
+                s.finished
+            })
+            .await?
+            .ok_or(Error::Timeout("ping"))?;
+        Ok(PingResult::new(status, count))
+    }
+
+    /// Trace the route from the router to `target` and wait for the results.
+    ///
+    /// `target` can be either a hostname or an IP address. This can take a few minutes if many hops don't respond.
+    pub async fn traceroute(&self, target: &str) -> Result<Vec<TracerouteHop>> {
+        validation::diagnostic_target(target)?;
+        let fields = [("Target".into(), target.into())];
+        let resp = self
+            .xml_setter(functions::START_TRACEROUTE, Some(&fields))
+            .await?;
+        if !resp.is_empty() {
+            return Err(Error::Remote(resp));
+        }
+        let status = self
+            .poll_getter(
+                functions::TRACEROUTE_RESULT,
+                TRACEROUTE_TIMEOUT,
+                |s: &models::TracerouteStatus| s.finished,
+            )
+            .await?
+            .ok_or(Error::Timeout("traceroute"))?;
+        Ok(status.hops)
+    }
+
     /// Get all devices connected to the router.
     pub async fn devices(&self) -> Result<models::LanUserTable> {
         self.xml_getter(functions::LAN_TABLE).await
@@ -200,34 +380,431 @@ impl ConnectBox {
         self.xml_getter(functions::FORWARDS).await
     }
 
+    /// Get the status of all downstream channels the modem is locked to.
+    pub async fn downstream_channels(&self) -> Result<models::DownstreamChannels> {
+        status::downstream_channels(self).await
+    }
+
+    /// Get the status of all upstream channels the modem is transmitting on.
+    pub async fn upstream_channels(&self) -> Result<models::UpstreamChannels> {
+        status::upstream_channels(self).await
+    }
+
+    /// Get the modem's system information, such as the firmware version, uptime and provisioning status.
+    pub async fn system_info(&self) -> Result<models::SystemInfo> {
+        status::system_info(self).await
+    }
+
+    /// Get the wireless settings of both the 2.4 GHz and 5 GHz radios.
+    pub async fn wireless_settings(&self) -> Result<models::WirelessSettings> {
+        self.xml_getter(functions::WIRELESS_BASIC).await
+    }
+
+    /// Apply wireless settings for both radios.
+    ///
+    /// The settings of both radios are validated before being sent, so this fails if either of them uses a security mode this library doesn't support. Read-only fields like [`RadioSettings::current_channel`](models::RadioSettings::current_channel) are ignored.
+    /// Most of the time, you will want to use [`edit_wireless_settings`](Self::edit_wireless_settings()) instead.
+    pub async fn set_wireless_settings(&self, settings: &WirelessSettings) -> Result<()> {
+        validation::radio(WifiBand::Band2_4GHz, &settings.band_2g)?;
+        validation::radio(WifiBand::Band5GHz, &settings.band_5g)?;
+        self.apply_wireless_settings(settings).await
+    }
+
+    async fn apply_wireless_settings(&self, settings: &WirelessSettings) -> Result<()> {
+        let mut fields: Vec<Field> = Vec::new();
+        for (suffix, radio) in [("2g", &settings.band_2g), ("5g", &settings.band_5g)] {
+            let channel = radio.channel.unwrap_or(0);
+            fields.extend([
+                (
+                    format!("wlBssEnable{suffix}").into(),
+                    u8::from(radio.enabled).to_string().into(),
+                ),
+                (format!("wlSsid{suffix}").into(), radio.ssid.as_str().into()),
+                (
+                    format!("wlHiddenSsid{suffix}").into(),
+                    u8::from(!radio.broadcast_ssid).to_string().into(),
+                ),
+                (
+                    format!("wlSecurity{suffix}").into(),
+                    radio.security.id_str(),
+                ),
+                (
+                    format!("wlPskey{suffix}").into(),
+                    radio.passphrase.as_str().into(),
+                ),
+                (
+                    format!("wlChannel{suffix}").into(),
+                    channel.to_string().into(),
+                ),
+                (
+                    format!("wlBandwidth{suffix}").into(),
+                    radio.channel_width.id_str().into(),
+                ),
+                (
+                    format!("wlTxMode{suffix}").into(),
+                    radio.mode.id_str().into(),
+                ),
+                (
+                    format!("wlTxPower{suffix}").into(),
+                    radio.transmit_power.id_str().into(),
+                ),
+            ]);
+        }
+        let resp = self
+            .xml_setter(functions::EDIT_WIRELESS_BASIC, Some(&fields))
+            .await?;
+        if resp.is_empty() {
+            Ok(())
+        } else {
+            Err(Error::Remote(resp))
+        }
+    }
+
+    /// Edit the wireless settings.
+    ///
+    /// The current settings are retrieved and passed to `f`, which can modify them as needed. The modified settings are then validated and applied.
+    /// Only the radios whose settings were changed are validated, so a radio with settings this library doesn't support is sent back unchanged.
+    pub async fn edit_wireless_settings<F>(&self, f: F) -> Result<()>
+    where
+        F: FnOnce(&mut WirelessSettings),
+    {
+        let mut settings = self.wireless_settings().await?;
+        let original = settings.clone();
+        f(&mut settings);
+        for band in [WifiBand::Band2_4GHz, WifiBand::Band5GHz] {
+            if settings.band(band) != original.band(band) {
+                validation::radio(band, settings.band(band))?;
+            }
+        }
+        self.apply_wireless_settings(&settings).await
+    }
+
+    /// Change the SSID of a radio and whether it is broadcast.
+    pub async fn set_ssid(&self, band: WifiBand, ssid: &str, broadcast: bool) -> Result<()> {
+        validation::ssid(ssid)?;
+        self.edit_wireless_settings(|s| {
+            let radio = s.band_mut(band);
+            radio.ssid = ssid.to_string();
+            radio.broadcast_ssid = broadcast;
+        })
+        .await
+    }
+
+    /// Change the security mode and passphrase of a radio. The passphrase is ignored for open networks.
+    pub async fn set_wifi_security(
+        &self,
+        band: WifiBand,
+        security: WifiSecurity,
+        passphrase: &str,
+    ) -> Result<()> {
+        validation::passphrase(security, passphrase)?;
+        self.edit_wireless_settings(|s| {
+            let radio = s.band_mut(band);
+            radio.security = security;
+            if security != WifiSecurity::Open {
+                radio.passphrase = passphrase.to_string();
+            }
+        })
+        .await
+    }
+
+    /// Change the channel, channel width, 802.11 mode and transmit power of a radio.
+    ///
+    /// Returns the settings of the radio as read back from the router after applying them.
+    pub async fn tune_radio(&self, band: WifiBand, tuning: &RadioTuning) -> Result<RadioSettings> {
+        self.edit_wireless_settings(|s| {
+            let radio = s.band_mut(band);
+            radio.channel = tuning.channel;
+            radio.channel_width = tuning.channel_width;
+            radio.mode = tuning.mode;
+            radio.transmit_power = tuning.transmit_power;
+        })
+        .await?;
+        let settings = self.wireless_settings().await?;
+        Ok(settings.band(band).clone())
+    }
+
+    /// Get all guest networks.
+    pub async fn guest_networks(&self) -> Result<models::GuestNetworks> {
+        self.xml_getter(functions::GUEST_NETWORK).await
+    }
+
+    /// Apply the settings of a guest network. The network to modify is selected by its `index` and `band`.
+    ///
+    /// To change only some settings, retrieve the network with [`guest_networks`](Self::guest_networks()), modify it and pass it to this method.
+    pub async fn set_guest_network(&self, network: &GuestNetwork) -> Result<()> {
+        validation::ssid(&network.ssid)?;
+        validation::security(network.band, network.security)?;
+        validation::passphrase(network.security, &network.passphrase)?;
+        let fields = [
+            ("GuestIndex".into(), network.index.to_string().into()),
+            ("GuestBand".into(), network.band.id_str().into()),
+            (
+                "GuestEnable".into(),
+                u8::from(network.enabled).to_string().into(),
+            ),
+            ("GuestSsid".into(), network.ssid.as_str().into()),
+            ("GuestSecurity".into(), network.security.id_str()),
+            ("GuestPskey".into(), network.passphrase.as_str().into()),
+            (
+                "GuestIsolation".into(),
+                u8::from(network.isolation).to_string().into(),
+            ),
+        ];
+        let resp = self
+            .xml_setter(functions::EDIT_GUEST_NETWORK, Some(&fields))
+            .await?;
+        if resp.is_empty() {
+            Ok(())
+        } else {
+            Err(Error::Remote(resp))
+        }
+    }
+
+    /// Get the WPS configuration and the state of the last WPS session.
+    pub async fn wps_status(&self) -> Result<models::WpsStatus> {
+        self.xml_getter(functions::WPS).await
+    }
+
+    /// Enable or disable WPS on all radios.
+    pub async fn set_wps_enabled(&self, enabled: bool) -> Result<()> {
+        let fields = [("WpsEnable".into(), u8::from(enabled).to_string().into())];
+        let resp = self.xml_setter(functions::EDIT_WPS, Some(&fields)).await?;
+        if resp.is_empty() {
+            Ok(())
+        } else {
+            Err(Error::Remote(resp))
+        }
+    }
+
+    /// Start a WPS push-button session on the specified band.
+    ///
+    /// The session lasts two minutes, use [`wps_status`](Self::wps_status()) to check whether a client has paired.
+    pub async fn start_wps_push_button(&self, band: WifiBand) -> Result<()> {
+        let fields = [
+            ("WpsMethod".into(), "1".into()),
+            ("WpsBand".into(), band.id_str().into()),
+            ("WpsPin".into(), "".into()),
+        ];
+        let resp = self.xml_setter(functions::START_WPS, Some(&fields)).await?;
+        if resp.is_empty() {
+            Ok(())
+        } else {
+            Err(Error::Remote(resp))
+        }
+    }
+
+    /// Start a WPS PIN session on the specified band, using the PIN of the client device.
+    ///
+    /// The session lasts two minutes, use [`wps_status`](Self::wps_status()) to check whether the client has paired.
+    pub async fn start_wps_pin(&self, band: WifiBand, pin: &str) -> Result<()> {
+        validation::wps_pin(pin)?;
+        let fields = [
+            ("WpsMethod".into(), "2".into()),
+            ("WpsBand".into(), band.id_str().into()),
+            ("WpsPin".into(), pin.into()),
+        ];
+        let resp = self.xml_setter(functions::START_WPS, Some(&fields)).await?;
+        if resp.is_empty() {
+            Ok(())
+        } else {
+            Err(Error::Remote(resp))
+        }
+    }
+
+    /// Scan for neighbouring access points on the specified band.
+    ///
+    /// The scan takes a few seconds, during which the radio may be briefly unavailable to clients.
+    pub async fn wifi_scan(&self, band: WifiBand) -> Result<Vec<AccessPoint>> {
+        let fields = [("band".into(), band.id_str().into())];
+        let resp = self
+            .xml_setter(functions::START_WIFI_SCAN, Some(&fields))
+            .await?;
+        if !resp.is_empty() {
+            return Err(Error::Remote(resp));
+        }
+        let scan = self
+            .poll_getter(
+                functions::WIFI_SCAN,
+                WIFI_SCAN_TIMEOUT,
+                |s: &models::WifiScan| s.finished,
+            )
+            .await?
+            .ok_or(Error::Timeout("Wi-Fi scan"))?;
+        Ok(scan.access_points)
+    }
+
+    /// Scan the specified band and recommend the channel with the least interference. See [`channel_planner`] for details.
+    ///
+    /// If `apply` is true and the recommended channel differs from the current one, the radio is switched to it.
+    pub async fn optimize_wifi_channel(
+        &self,
+        band: WifiBand,
+        apply: bool,
+    ) -> Result<ChannelRecommendation> {
+        let settings = self.wireless_settings().await?;
+        let neighbours = self.wifi_scan(band).await?;
+        let recommendation =
+            channel_planner::recommend_channel(band, settings.band(band), &neighbours);
+        if apply && recommendation.is_change() {
+            tracing::info!(
+                "switching the {band} radio from channel {} to {}",
+                recommendation.current_channel,
+                recommendation.recommended_channel
+            );
+            self.edit_wireless_settings(|s| {
+                s.band_mut(band).channel = Some(recommendation.recommended_channel);
+            })
+            .await?;
+        }
+        Ok(recommendation)
+    }
+
+    /// Get the LAN and DHCP server settings.
+    pub async fn lan_settings(&self) -> Result<LanSettings> {
+        self.xml_getter(functions::LAN_SETTINGS).await
+    }
+
+    /// Apply LAN and DHCP server settings. The settings are validated before being sent.
+    ///
+    /// Note that changing [`LanSettings::lan_ip`] also changes the address of the router's web interface. Create a new client with the new address to continue managing the router.
+    pub async fn set_lan_settings(&self, settings: &LanSettings) -> Result<()> {
+        validation::lan_settings(settings)?;
+        let mut dns_servers = String::new();
+        for server in &settings.dns_servers {
+            dns_servers.push_star(&server.to_string());
+        }
+        let fields = [
+            ("LanIP".into(), settings.lan_ip.to_string().into()),
+            ("SubnetMask".into(), settings.subnet_mask.to_string().into()),
+            (
+                "DhcpEnable".into(),
+                u8::from(settings.dhcp_enabled).to_string().into(),
+            ),
+            ("DhcpStartIP".into(), settings.pool_start.to_string().into()),
+            ("DhcpEndIP".into(), settings.pool_end.to_string().into()),
+            (
+                "LeaseTime".into(),
+                settings.lease_time.as_secs().to_string().into(),
+            ),
+            ("DNSServers".into(), dns_servers.into()),
+        ];
+        let resp = self
+            .xml_setter(functions::EDIT_LAN_SETTINGS, Some(&fields))
+            .await?;
+        if resp.is_empty() {
+            Ok(())
+        } else {
+            Err(Error::Remote(resp))
+        }
+    }
+
+    /// Edit the LAN and DHCP server settings.
+    ///
+    /// The current settings are retrieved and passed to `f`, which can modify them as needed. The modified settings are then validated and applied.
+    pub async fn edit_lan_settings<F>(&self, f: F) -> Result<()>
+    where
+        F: FnOnce(&mut LanSettings),
+    {
+        let mut settings = self.lan_settings().await?;
+        f(&mut settings);
+        self.set_lan_settings(&settings).await
+    }
+
+    /// Get the IPv4 status of the WAN interface, including the public address of the modem.
+    pub async fn wan_status(&self) -> Result<models::WanStatus> {
+        self.xml_getter(functions::WAN_STATUS).await
+    }
+
+    /// Get the IPv6 status of the WAN interface.
+    pub async fn ipv6_wan_status(&self) -> Result<models::Ipv6WanStatus> {
+        self.xml_getter(functions::IPV6_WAN_STATUS).await
+    }
+
+    /// Get the IPv6 settings of the LAN.
+    pub async fn ipv6_lan_settings(&self) -> Result<Ipv6LanSettings> {
+        self.xml_getter(functions::IPV6_LAN_SETTINGS).await
+    }
+
+    /// Apply IPv6 settings of the LAN. The settings are validated before being sent. [`Ipv6LanSettings::prefix`] is read-only and ignored.
+    pub async fn set_ipv6_lan_settings(&self, settings: &Ipv6LanSettings) -> Result<()> {
+        validation::ipv6_lan_settings(settings)?;
+        let mut dns_servers = String::new();
+        for server in &settings.dns_servers {
+            dns_servers.push_star(&server.to_string());
+        }
+        let fields = [
+            ("AddressMode".into(), settings.address_mode.id_str().into()),
+            (
+                "LeaseTime".into(),
+                settings.lease_time.as_secs().to_string().into(),
+            ),
+            ("DNSServers".into(), dns_servers.into()),
+        ];
+        let resp = self
+            .xml_setter(functions::EDIT_IPV6_LAN_SETTINGS, Some(&fields))
+            .await?;
+        if resp.is_empty() {
+            Ok(())
+        } else {
+            Err(Error::Remote(resp))
+        }
+    }
+
+    /// Get all DHCP reservations.
+    pub async fn dhcp_reservations(&self) -> Result<models::DhcpReservations> {
+        self.xml_getter(functions::DHCP_RESERVATIONS).await
+    }
+
+    /// Reserve an IPv4 address for a device. The address must be in the LAN subnet.
+    pub async fn add_dhcp_reservation(&self, reservation: &DhcpReservation) -> Result<()> {
+        let lan = self.port_forwards().await?;
+        validation::lan_host(reservation.ip, lan.lan_ip, lan.subnet_mask)?;
+        let fields = [
+            ("action".into(), "add".into()),
+            ("MACAddr".into(), reservation.mac.to_string().into()),
+            ("IPAddr".into(), reservation.ip.to_string().into()),
+        ];
+        let resp = self
+            .xml_setter(functions::EDIT_DHCP_RESERVATIONS, Some(&fields))
+            .await?;
+        if resp.is_empty() {
+            Ok(())
+        } else {
+            Err(Error::Remote(resp))
+        }
+    }
+
+    /// Remove the DHCP reservation of a device.
+    pub async fn remove_dhcp_reservation(&self, mac: MacAddress) -> Result<()> {
+        let fields = [
+            ("action".into(), "delete".into()),
+            ("MACAddr".into(), mac.to_string().into()),
+            ("IPAddr".into(), "".into()),
+        ];
+        let resp = self
+            .xml_setter(functions::EDIT_DHCP_RESERVATIONS, Some(&fields))
+            .await?;
+        if resp.is_empty() {
+            Ok(())
+        } else {
+            Err(Error::Remote(resp))
+        }
+    }
+
     /// Toggle or remove port forwards.
     ///
-    /// This function accepts a predicate that will be called for every existing port forward. It should decide what to do with each port forward and return a [`PortForwardAction`].
+    /// This function accepts a predicate that will be called for every existing port forward. It should decide what to do with each port forward and return an [`EditAction`].
     pub async fn edit_port_forwards<F>(&self, mut f: F) -> Result<()>
     where
-        F: FnMut(models::PortForwardEntry) -> PortForwardAction,
+        F: FnMut(models::PortForwardEntry) -> EditAction,
     {
         let mut instance = String::new();
         let mut enable = String::new();
         let mut delete = String::new();
         for entry in self.port_forwards().await?.entries {
             let id = entry.id;
-            match f(entry) {
-                PortForwardAction::Enable => {
-                    enable.push_star("1");
-                    delete.push_star("0");
-                }
-                PortForwardAction::Disable => {
-                    enable.push_star("0");
-                    delete.push_star("0");
-                }
-                PortForwardAction::Delete => {
-                    enable.push_star("0");
-                    delete.push_star("1");
-                }
-                PortForwardAction::Keep => continue,
-            }
-            instance.push_star(&id.to_string());
+            f(entry).push_fields(id, &mut instance, &mut enable, &mut delete);
         }
         let fields = [
             ("action".into(), "apply".into()),
@@ -276,21 +853,398 @@ impl ConnectBox {
             Err(Error::Remote(resp))
         }
     }
+
+    /// Get the DMZ host, or `None` if the DMZ is disabled.
+    pub async fn dmz(&self) -> Result<Option<Ipv4Addr>> {
+        let dmz: models::Dmz = self.xml_getter(functions::DMZ).await?;
+        Ok(dmz.host.filter(|_| dmz.enable))
+    }
+
+    /// Set the DMZ host. All incoming traffic that doesn't match a port forward will be forwarded to it.
+    ///
+    /// The host must be in the LAN subnet. Pass `None` to disable the DMZ.
+    pub async fn set_dmz(&self, host: Option<Ipv4Addr>) -> Result<()> {
+        if let Some(host) = host {
+            let lan = self.port_forwards().await?;
+            validation::lan_host(host, lan.lan_ip, lan.subnet_mask)?;
+        }
+        let fields = [
+            ("enable".into(), u8::from(host.is_some()).to_string().into()),
+            (
+                "IP".into(),
+                host.map(|h| h.to_string()).unwrap_or_default().into(),
+            ),
+        ];
+        let resp = self.xml_setter(functions::EDIT_DMZ, Some(&fields)).await?;
+        if resp.is_empty() {
+            Ok(())
+        } else {
+            Err(Error::Remote(resp))
+        }
+    }
+
+    /// Get whether UPnP is enabled, along with the port mappings created through it.
+    pub async fn upnp(&self) -> Result<models::UpnpStatus> {
+        self.xml_getter(functions::UPNP).await
+    }
+
+    /// Enable or disable UPnP.
+    pub async fn set_upnp_enabled(&self, enabled: bool) -> Result<()> {
+        let fields = [("Enable".into(), u8::from(enabled).to_string().into())];
+        let resp = self.xml_setter(functions::EDIT_UPNP, Some(&fields)).await?;
+        if resp.is_empty() {
+            Ok(())
+        } else {
+            Err(Error::Remote(resp))
+        }
+    }
+
+    /// Get all IP filtering rules.
+    pub async fn ip_filters(&self) -> Result<models::IpFilters> {
+        self.xml_getter(functions::IP_FILTERS).await
+    }
+
+    /// Toggle or remove IP filtering rules.
+    ///
+    /// This function accepts a predicate that will be called for every existing rule. It should decide what to do with each rule and return an [`EditAction`].
+    pub async fn edit_ip_filters<F>(&self, mut f: F) -> Result<()>
+    where
+        F: FnMut(models::IpFilterEntry) -> EditAction,
+    {
+        let mut instance = String::new();
+        let mut enable = String::new();
+        let mut delete = String::new();
+        for entry in self.ip_filters().await?.entries {
+            let id = entry.id;
+            f(entry).push_fields(id, &mut instance, &mut enable, &mut delete);
+        }
+        let fields = [
+            ("action".into(), "apply".into()),
+            ("instance".into(), instance.into()),
+            ("src_addr_s".into(), "".into()),
+            ("src_addr_e".into(), "".into()),
+            ("dst_port_s".into(), "".into()),
+            ("dst_port_e".into(), "".into()),
+            ("protocol".into(), "".into()),
+            ("enable".into(), enable.into()),
+            ("delete".into(), delete.into()),
+        ];
+        let resp = self
+            .xml_setter(functions::EDIT_IP_FILTERS, Some(&fields))
+            .await?;
+        if resp.is_empty() {
+            Ok(())
+        } else {
+            Err(Error::Remote(resp))
+        }
+    }
+
+    /// Add an IP filtering rule. The `id` field of the rule is ignored, and the address range must be in the LAN subnet.
+    ///
+    /// Both the address range and the port range must be in ascending order.
+    pub async fn add_ip_filter(&self, filter: &IpFilterEntry) -> Result<()> {
+        let lan = self.port_forwards().await?;
+        validation::ip_filter(filter, lan.lan_ip, lan.subnet_mask)?;
+        let fields = [
+            ("action".into(), "add".into()),
+            ("instance".into(), "".into()),
+            ("src_addr_s".into(), filter.start_ip.to_string().into()),
+            ("src_addr_e".into(), filter.end_ip.to_string().into()),
+            ("dst_port_s".into(), filter.start_port.to_string().into()),
+            ("dst_port_e".into(), filter.end_port.to_string().into()),
+            ("protocol".into(), filter.protocol.id_str().into()),
+            ("enable".into(), u8::from(filter.enable).to_string().into()),
+            ("delete".into(), "0".into()),
+        ];
+        let resp = self
+            .xml_setter(functions::EDIT_IP_FILTERS, Some(&fields))
+            .await?;
+        if resp.is_empty() {
+            Ok(())
+        } else {
+            Err(Error::Remote(resp))
+        }
+    }
+
+    /// Get all MAC filtering rules.
+    pub async fn mac_filters(&self) -> Result<models::MacFilters> {
+        self.xml_getter(functions::MAC_FILTERS).await
+    }
+
+    /// Toggle or remove MAC filtering rules.
+    ///
+    /// This function accepts a predicate that will be called for every existing rule. It should decide what to do with each rule and return an [`EditAction`].
+    pub async fn edit_mac_filters<F>(&self, mut f: F) -> Result<()>
+    where
+        F: FnMut(models::MacFilterEntry) -> EditAction,
+    {
+        let mut instance = String::new();
+        let mut enable = String::new();
+        let mut delete = String::new();
+        for entry in self.mac_filters().await?.entries {
+            let id = entry.id;
+            f(entry).push_fields(id, &mut instance, &mut enable, &mut delete);
+        }
+        let fields = [
+            ("action".into(), "apply".into()),
+            ("instance".into(), instance.into()),
+            ("MACAddr".into(), "".into()),
+            ("enable".into(), enable.into()),
+            ("delete".into(), delete.into()),
+        ];
+        let resp = self
+            .xml_setter(functions::EDIT_MAC_FILTERS, Some(&fields))
+            .await?;
+        if resp.is_empty() {
+            Ok(())
+        } else {
+            Err(Error::Remote(resp))
+        }
+    }
+
+    /// Add a MAC filtering rule. The `id` field of the rule is ignored.
+    pub async fn add_mac_filter(&self, filter: &MacFilterEntry) -> Result<()> {
+        let fields = [
+            ("action".into(), "add".into()),
+            ("instance".into(), "".into()),
+            ("MACAddr".into(), filter.mac.to_string().into()),
+            ("enable".into(), u8::from(filter.enable).to_string().into()),
+            ("delete".into(), "0".into()),
+        ];
+        let resp = self
+            .xml_setter(functions::EDIT_MAC_FILTERS, Some(&fields))
+            .await?;
+        if resp.is_empty() {
+            Ok(())
+        } else {
+            Err(Error::Remote(resp))
+        }
+    }
+
+    /// Get the parental control settings: access schedules and blocked keywords and URLs.
+    pub async fn parental_control(&self) -> Result<models::ParentalControl> {
+        self.xml_getter(functions::PARENTAL_CONTROL).await
+    }
+
+    /// Enable or disable parental control as a whole, without changing its rules.
+    pub async fn set_parental_control_enabled(&self, enabled: bool) -> Result<()> {
+        let fields = [("Enable".into(), u8::from(enabled).to_string().into())];
+        let resp = self
+            .xml_setter(functions::EDIT_PARENTAL_CONTROL, Some(&fields))
+            .await?;
+        if resp.is_empty() {
+            Ok(())
+        } else {
+            Err(Error::Remote(resp))
+        }
+    }
+
+    /// Toggle or remove access schedules.
+    ///
+    /// This function accepts a predicate that will be called for every existing schedule. It should decide what to do with each schedule and return an [`EditAction`].
+    pub async fn edit_access_schedules<F>(&self, mut f: F) -> Result<()>
+    where
+        F: FnMut(models::AccessSchedule) -> EditAction,
+    {
+        let mut instance = String::new();
+        let mut enable = String::new();
+        let mut delete = String::new();
+        for entry in self.parental_control().await?.schedules {
+            let id = entry.id;
+            f(entry).push_fields(id, &mut instance, &mut enable, &mut delete);
+        }
+        let fields = [
+            ("action".into(), "apply".into()),
+            ("instance".into(), instance.into()),
+            ("name".into(), "".into()),
+            ("MACAddr".into(), "".into()),
+            ("days".into(), "".into()),
+            ("start".into(), "".into()),
+            ("end".into(), "".into()),
+            ("enable".into(), enable.into()),
+            ("delete".into(), delete.into()),
+        ];
+        let resp = self
+            .xml_setter(functions::EDIT_ACCESS_SCHEDULES, Some(&fields))
+            .await?;
+        if resp.is_empty() {
+            Ok(())
+        } else {
+            Err(Error::Remote(resp))
+        }
+    }
+
+    /// Add an access schedule. The `id` field of the schedule is ignored.
+    ///
+    /// The blocked range may wrap past midnight (see [`TimeRange`](models::TimeRange)), in which case it starts on
+    /// the selected days and ends on the following mornings.
+    pub async fn add_access_schedule(&self, schedule: &AccessSchedule) -> Result<()> {
+        validation::schedule(schedule)?;
+        let fields = [
+            ("action".into(), "add".into()),
+            ("instance".into(), "".into()),
+            ("name".into(), schedule.name.as_str().into()),
+            ("MACAddr".into(), schedule.mac.to_string().into()),
+            ("days".into(), schedule.days.bits().to_string().into()),
+            ("start".into(), schedule.blocked.start.to_string().into()),
+            ("end".into(), schedule.blocked.end.to_string().into()),
+            (
+                "enable".into(),
+                u8::from(schedule.enable).to_string().into(),
+            ),
+            ("delete".into(), "0".into()),
+        ];
+        let resp = self
+            .xml_setter(functions::EDIT_ACCESS_SCHEDULES, Some(&fields))
+            .await?;
+        if resp.is_empty() {
+            Ok(())
+        } else {
+            Err(Error::Remote(resp))
+        }
+    }
+
+    /// Replace the lists of blocked keywords and URLs.
+    pub async fn set_content_filter<K, U>(&self, keywords: &[K], urls: &[U]) -> Result<()>
+    where
+        K: AsRef<str>,
+        U: AsRef<str>,
+    {
+        let mut keyword_list = String::new();
+        for keyword in keywords {
+            validation::keyword(keyword.as_ref())?;
+            keyword_list.push_star(keyword.as_ref());
+        }
+        let mut url_list = String::new();
+        for url in urls {
+            validation::keyword(url.as_ref())?;
+            url_list.push_star(url.as_ref());
+        }
+        let fields = [
+            ("Keyword".into(), keyword_list.into()),
+            ("URL".into(), url_list.into()),
+        ];
+        let resp = self
+            .xml_setter(functions::EDIT_CONTENT_FILTER, Some(&fields))
+            .await?;
+        if resp.is_empty() {
+            Ok(())
+        } else {
+            Err(Error::Remote(resp))
+        }
+    }
+
+    /// Get the firewall and VPN passthrough settings.
+    pub async fn firewall_settings(&self) -> Result<FirewallSettings> {
+        self.xml_getter(functions::FIREWALL).await
+    }
+
+    /// Apply firewall and VPN passthrough settings.
+    pub async fn set_firewall_settings(&self, settings: &FirewallSettings) -> Result<()> {
+        let flag = |enabled: bool| u8::from(enabled).to_string().into();
+        let fields = [
+            ("firewallProtection".into(), flag(settings.ipv4_firewall)),
+            ("firewallProtectionV6".into(), flag(settings.ipv6_firewall)),
+            ("blockIcmp".into(), flag(settings.block_ping)),
+            ("floodProtection".into(), flag(settings.flood_protection)),
+            (
+                "portScanProtection".into(),
+                flag(settings.port_scan_protection),
+            ),
+            (
+                "blockFragIPPackets".into(),
+                flag(settings.block_fragmented_packets),
+            ),
+            ("IPSecPassthrough".into(), flag(settings.ipsec_passthrough)),
+            ("PPTPPassthrough".into(), flag(settings.pptp_passthrough)),
+            ("L2TPPassthrough".into(), flag(settings.l2tp_passthrough)),
+        ];
+        let resp = self
+            .xml_setter(functions::EDIT_FIREWALL, Some(&fields))
+            .await?;
+        if resp.is_empty() {
+            Ok(())
+        } else {
+            Err(Error::Remote(resp))
+        }
+    }
 }
 
-/// Specifies the action to perform with a given port forward. Used in conjunction with [`ConnectBox::edit_port_forwards`]
+/// Specifies the action to perform with an existing port forward, filtering rule or access schedule. Used in conjunction with [`ConnectBox::edit_port_forwards`], [`ConnectBox::edit_ip_filters`], [`ConnectBox::edit_mac_filters`] and [`ConnectBox::edit_access_schedules`]
 #[derive(Debug, Clone, Copy)]
-pub enum PortForwardAction {
-    /// Don't do anything with the port forward
+pub enum EditAction {
+    /// Don't do anything with the entry
     Keep,
-    /// Enable the port forward
+    /// Enable the entry
     Enable,
-    /// Disable the port forward
+    /// Disable the entry
     Disable,
-    /// Delete the port forward
+    /// Delete the entry
     Delete,
 }
 
+/// The action to perform with a given port forward, kept for compatibility
+pub type PortForwardAction = EditAction;
+
+impl EditAction {
+    /// Append the instance id and the enable and delete flags for this action to the request fields, unless the entry should be kept as it is.
+    fn push_fields(self, id: u32, instance: &mut String, enable: &mut String, delete: &mut String) {
+        let (enable_flag, delete_flag) = match self {
+            EditAction::Enable => ("1", "0"),
+            EditAction::Disable => ("0", "0"),
+            EditAction::Delete => ("0", "1"),
+            EditAction::Keep => return,
+        };
+        instance.push_star(&id.to_string());
+        enable.push_star(enable_flag);
+        delete.push_star(delete_flag);
+    }
+}
+
+/// Explicit confirmation required by [`ConnectBox::factory_reset`].
+///
+/// It can only be obtained through [`FactoryResetConfirmation::erase_all_settings`] and is consumed by the reset, so every factory reset has to be asked for deliberately.
+#[derive(Debug)]
+pub struct FactoryResetConfirmation(());
+
+impl FactoryResetConfirmation {
+    /// Confirm that all settings of the modem should be erased.
+    #[must_use]
+    pub fn erase_all_settings() -> Self {
+        Self(())
+    }
+}
+
+impl status::XmlGetter for ConnectBox {
+    async fn xml_getter<T: DeserializeOwned>(&self, function: u32) -> Result<T> {
+        ConnectBox::xml_getter(self, function).await
+    }
+}
+
+/// Create the HTTP client used to talk to the web interface, storing cookies in `cookie_store`.
+fn http_client(cookie_store: &Arc<Jar>) -> Result<Client> {
+    Ok(Client::builder()
+        .user_agent("Mozilla/5.0")
+        .redirect(Policy::none())
+        .cookie_provider(cookie_store.clone())
+        .build()?)
+}
+
+fn find_cookie(cookie_store: &Jar, url: &Url, name: &str) -> Result<Option<String>> {
+    let Some(cookies) = cookie_store.cookies(url) else {
+        return Ok(None)
+    };
+    let cookies = cookies.to_str()?;
+    let Some(mut cookie_start) = cookies.find(&format!("{name}=")) else {
+        return Ok(None)
+    };
+    cookie_start += name.len() + 1;
+    let cookie_end = cookies[cookie_start..]
+        .find(';')
+        .map_or(cookies.len(), |p| p + cookie_start);
+    Ok(Some(cookies[cookie_start..cookie_end].to_string()))
+}
+
 trait StringExt {
     fn push_star(&mut self, string: &str);
 }
@@ -1,4 +1,11 @@
-use std::{fmt::Display, net::Ipv4Addr, time::Duration};
+use std::{
+    borrow::Cow,
+    fmt::Display,
+    net::{IpAddr, Ipv4Addr, Ipv6Addr},
+    ops::{BitOr, BitOrAssign},
+    str::FromStr,
+    time::Duration,
+};
 
 use serde::{
     de::{self, Error, Unexpected},
@@ -114,55 +121,1639 @@ impl Display for PortForwardProtocol {
     }
 }
 
-fn bool_from_int<'de, D>(deserializer: D) -> Result<bool, D::Error>
-where
-    D: Deserializer<'de>,
-{
-    match u8::deserialize(deserializer)? {
-        0 => Ok(false),
-        1 => Ok(true),
-        other => Err(de::Error::invalid_value(
-            Unexpected::Unsigned(u64::from(other)),
-            &"zero or one",
-        )),
+#[derive(Deserialize, Debug)]
+pub(crate) struct Dmz {
+    #[serde(deserialize_with = "bool_from_int")]
+    pub enable: bool,
+    #[serde(rename = "IP")]
+    #[serde(deserialize_with = "optional_ipv4")]
+    pub host: Option<Ipv4Addr>,
+}
+
+#[derive(Deserialize, Debug)]
+pub struct UpnpStatus {
+    #[serde(rename = "Enable")]
+    #[serde(deserialize_with = "bool_from_int")]
+    pub enabled: bool,
+    /// Port mappings currently created by devices through UPnP. Empty if the firmware doesn't report them
+    #[serde(rename = "mapping")]
+    #[serde(default = "Vec::default")]
+    pub mappings: Vec<UpnpMapping>,
+}
+
+#[derive(Deserialize, Debug)]
+pub struct UpnpMapping {
+    #[serde(rename = "local_IP")]
+    pub local_ip: Ipv4Addr,
+    pub external_port: u16,
+    pub internal_port: u16,
+    pub protocol: PortForwardProtocol,
+    #[serde(default)]
+    pub description: String,
+}
+
+#[derive(Deserialize, Debug)]
+pub struct IpFilters {
+    #[serde(rename = "instance")]
+    #[serde(default = "Vec::default")]
+    pub entries: Vec<IpFilterEntry>,
+}
+
+/// Blocks outgoing traffic from a range of LAN addresses to a range of ports
+#[derive(Deserialize, Debug)]
+pub struct IpFilterEntry {
+    pub id: u32,
+    #[serde(rename = "src_addr_s")]
+    pub start_ip: Ipv4Addr,
+    #[serde(rename = "src_addr_e")]
+    pub end_ip: Ipv4Addr,
+    #[serde(rename = "dst_port_s")]
+    pub start_port: u16,
+    #[serde(rename = "dst_port_e")]
+    pub end_port: u16,
+    pub protocol: PortForwardProtocol,
+    #[serde(deserialize_with = "bool_from_int")]
+    pub enable: bool,
+}
+
+#[derive(Deserialize, Debug)]
+pub struct MacFilters {
+    #[serde(rename = "instance")]
+    #[serde(default = "Vec::default")]
+    pub entries: Vec<MacFilterEntry>,
+}
+
+/// Blocks all traffic from a device
+#[derive(Deserialize, Debug)]
+pub struct MacFilterEntry {
+    pub id: u32,
+    #[serde(rename = "MACAddr")]
+    pub mac: MacAddress,
+    #[serde(deserialize_with = "bool_from_int")]
+    pub enable: bool,
+}
+
+#[derive(Deserialize, Debug, Clone, PartialEq, Eq)]
+pub struct FirewallSettings {
+    #[serde(rename = "firewallProtection")]
+    #[serde(deserialize_with = "bool_from_int")]
+    pub ipv4_firewall: bool,
+    #[serde(rename = "firewallProtectionV6")]
+    #[serde(deserialize_with = "bool_from_int")]
+    pub ipv6_firewall: bool,
+    /// Don't respond to ICMP echo requests (ping) from the WAN
+    #[serde(rename = "blockIcmp")]
+    #[serde(deserialize_with = "bool_from_int")]
+    pub block_ping: bool,
+    #[serde(rename = "floodProtection")]
+    #[serde(deserialize_with = "bool_from_int")]
+    pub flood_protection: bool,
+    #[serde(rename = "portScanProtection")]
+    #[serde(deserialize_with = "bool_from_int")]
+    pub port_scan_protection: bool,
+    #[serde(rename = "blockFragIPPackets")]
+    #[serde(deserialize_with = "bool_from_int")]
+    pub block_fragmented_packets: bool,
+    #[serde(rename = "IPSecPassthrough")]
+    #[serde(deserialize_with = "bool_from_int")]
+    pub ipsec_passthrough: bool,
+    #[serde(rename = "PPTPPassthrough")]
+    #[serde(deserialize_with = "bool_from_int")]
+    pub pptp_passthrough: bool,
+    #[serde(rename = "L2TPPassthrough")]
+    #[serde(deserialize_with = "bool_from_int")]
+    pub l2tp_passthrough: bool,
+}
+
+#[derive(Deserialize, Debug)]
+pub struct ParentalControl {
+    #[serde(rename = "Enable")]
+    #[serde(deserialize_with = "bool_from_int")]
+    pub enabled: bool,
+    #[serde(rename = "Schedule")]
+    #[serde(default = "Vec::default")]
+    pub schedules: Vec<AccessSchedule>,
+    #[serde(rename = "Keyword")]
+    #[serde(default = "Vec::default")]
+    pub blocked_keywords: Vec<String>,
+    #[serde(rename = "URL")]
+    #[serde(default = "Vec::default")]
+    pub blocked_urls: Vec<String>,
+}
+
+/// Blocks internet access of a device on the selected days during a time range
+#[derive(Deserialize, Debug, Clone)]
+#[serde(from = "RawAccessSchedule")]
+pub struct AccessSchedule {
+    pub id: u32,
+    pub name: String,
+    pub mac: MacAddress,
+    pub days: Weekdays,
+    pub blocked: TimeRange,
+    pub enable: bool,
+}
+
+#[derive(Deserialize)]
+struct RawAccessSchedule {
+    id: u32,
+    name: String,
+    #[serde(rename = "MACAddr")]
+    mac: MacAddress,
+    days: Weekdays,
+    start: TimeOfDay,
+    end: TimeOfDay,
+    #[serde(deserialize_with = "bool_from_int")]
+    enable: bool,
+}
+
+impl From<RawAccessSchedule> for AccessSchedule {
+    fn from(s: RawAccessSchedule) -> Self {
+        Self {
+            id: s.id,
+            name: s.name,
+            mac: s.mac,
+            days: s.days,
+            blocked: TimeRange {
+                start: s.start,
+                end: s.end,
+            },
+            enable: s.enable,
+        }
+    }
+}
+
+/// A set of days of the week
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
+pub struct Weekdays(u8);
+
+impl Weekdays {
+    pub const MONDAY: Self = Self(1);
+    pub const TUESDAY: Self = Self(1 << 1);
+    pub const WEDNESDAY: Self = Self(1 << 2);
+    pub const THURSDAY: Self = Self(1 << 3);
+    pub const FRIDAY: Self = Self(1 << 4);
+    pub const SATURDAY: Self = Self(1 << 5);
+    pub const SUNDAY: Self = Self(1 << 6);
+    /// Monday to Friday
+    pub const WORKDAYS: Self = Self(0b001_1111);
+    /// Saturday and Sunday
+    pub const WEEKEND: Self = Self(0b110_0000);
+    pub const ALL: Self = Self(0b111_1111);
+
+    #[must_use]
+    pub fn contains(self, other: Self) -> bool {
+        self.0 & other.0 == other.0
+    }
+
+    #[must_use]
+    pub fn is_empty(self) -> bool {
+        self.0 == 0
+    }
+
+    pub(crate) fn bits(self) -> u8 {
+        self.0
+    }
+}
+
+impl BitOr for Weekdays {
+    type Output = Self;
+
+    fn bitor(self, rhs: Self) -> Self {
+        Self(self.0 | rhs.0)
+    }
+}
+
+impl BitOrAssign for Weekdays {
+    fn bitor_assign(&mut self, rhs: Self) {
+        self.0 |= rhs.0;
+    }
+}
+
+impl<'de> Deserialize<'de> for Weekdays {
+    fn deserialize<D>(d: D) -> Result<Self, D::Error>
+    where
+        D: Deserializer<'de>,
+    {
+        match u8::deserialize(d)? {
+            bits @ 0..=0b111_1111 => Ok(Self(bits)),
+            _ => Err(D::Error::custom("weekday mask not in range 0..=127")),
+        }
+    }
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord)]
+pub struct TimeOfDay {
+    pub hour: u8,
+    pub minute: u8,
+}
+
+impl<'de> Deserialize<'de> for TimeOfDay {
+    fn deserialize<D>(d: D) -> Result<Self, D::Error>
+    where
+        D: Deserializer<'de>,
+    {
+        let s = String::deserialize(d)?;
+        let (hour, minute) = s
+            .split_once(':')
+            .ok_or_else(|| D::Error::custom(format!("invalid time of day {s:?}")))?;
+        Ok(Self {
+            hour: hour.parse().map_err(|e| D::Error::custom(format!("{e}")))?,
+            minute: minute
+                .parse()
+                .map_err(|e| D::Error::custom(format!("{e}")))?,
+        })
+    }
+}
+
+impl Display for TimeOfDay {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(f, "{:02}:{:02}", self.hour, self.minute)
+    }
+}
+
+/// A range of time within a day.
+///
+/// If `end` is earlier than `start`, the range wraps past midnight: 22:00–07:00 covers 22:00 until 07:00 the next
+/// morning, and 22:00–00:00 covers 22:00 until midnight. Such ranges are passed to the router as they are.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct TimeRange {
+    pub start: TimeOfDay,
+    pub end: TimeOfDay,
+}
+
+#[derive(Deserialize, Debug, Clone)]
+pub struct LanSettings {
+    #[serde(rename = "LanIP")]
+    pub lan_ip: Ipv4Addr,
+    #[serde(rename = "subnetmask")]
+    pub subnet_mask: Ipv4Addr,
+    #[serde(rename = "DHCP_Enable")]
+    #[serde(deserialize_with = "bool_from_int")]
+    pub dhcp_enabled: bool,
+    #[serde(rename = "DHCPStartIP")]
+    pub pool_start: Ipv4Addr,
+    #[serde(rename = "DHCPEndIP")]
+    pub pool_end: Ipv4Addr,
+    #[serde(rename = "leaseTime")]
+    #[serde(deserialize_with = "duration_from_secs")]
+    pub lease_time: Duration,
+    /// DNS servers handed out to DHCP clients
+    #[serde(rename = "DNSServer")]
+    #[serde(default = "Vec::default")]
+    pub dns_servers: Vec<Ipv4Addr>,
+}
+
+#[derive(Deserialize, Debug)]
+pub struct WanStatus {
+    /// Public IPv4 address of the modem, `None` if it's not connected
+    #[serde(rename = "IpAddress")]
+    #[serde(deserialize_with = "optional_ipv4")]
+    pub ip: Option<Ipv4Addr>,
+    #[serde(rename = "SubnetMask")]
+    #[serde(deserialize_with = "optional_ipv4")]
+    pub subnet_mask: Option<Ipv4Addr>,
+    /// Default gateway of the WAN connection, `None` if it's not connected
+    #[serde(rename = "DefaultGateway")]
+    #[serde(deserialize_with = "optional_ipv4")]
+    pub gateway: Option<Ipv4Addr>,
+    #[serde(rename = "DNSServer")]
+    #[serde(default = "Vec::default")]
+    pub dns_servers: Vec<Ipv4Addr>,
+    /// Time left until the DHCP lease of the public address expires, `None` if it's not connected
+    #[serde(rename = "LeaseRemaining")]
+    #[serde(deserialize_with = "optional_lease_time")]
+    pub lease_remaining: Option<Duration>,
+    #[serde(rename = "WanMAC")]
+    pub mac: MacAddress,
+}
+
+#[derive(Deserialize, Debug)]
+pub struct Ipv6WanStatus {
+    #[serde(rename = "WanIPv6Addr")]
+    #[serde(deserialize_with = "optional_ipv6")]
+    pub global_address: Option<Ipv6Addr>,
+    /// Prefix delegated by the ISP, which the LAN prefix is taken from
+    #[serde(rename = "DelegatedPrefix")]
+    #[serde(deserialize_with = "optional_ipv6_prefix")]
+    pub delegated_prefix: Option<Ipv6Prefix>,
+    #[serde(rename = "DefaultGatewayV6")]
+    #[serde(deserialize_with = "optional_ipv6")]
+    pub gateway: Option<Ipv6Addr>,
+    #[serde(rename = "DNSv6")]
+    #[serde(default = "Vec::default")]
+    pub dns_servers: Vec<Ipv6Addr>,
+}
+
+#[derive(Deserialize, Debug, Clone)]
+pub struct Ipv6LanSettings {
+    /// Prefix advertised in the LAN. It is derived from the delegated prefix and can't be changed
+    #[serde(rename = "LanPrefix")]
+    #[serde(deserialize_with = "optional_ipv6_prefix")]
+    pub prefix: Option<Ipv6Prefix>,
+    #[serde(rename = "AddressMode")]
+    pub address_mode: Ipv6AddressMode,
+    #[serde(rename = "DHCPv6LeaseTime")]
+    #[serde(deserialize_with = "duration_from_secs")]
+    pub lease_time: Duration,
+    /// DNS servers handed out to LAN clients
+    #[serde(rename = "DNSv6")]
+    #[serde(default = "Vec::default")]
+    pub dns_servers: Vec<Ipv6Addr>,
+}
+
+/// How LAN clients get their IPv6 addresses
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum Ipv6AddressMode {
+    /// Stateless address autoconfiguration only
+    Slaac,
+    /// SLAAC for addresses, with DHCPv6 providing additional information like DNS servers
+    StatelessDhcpv6,
+    /// Addresses are assigned by the DHCPv6 server
+    StatefulDhcpv6,
+}
+
+impl Ipv6AddressMode {
+    pub(crate) fn id_str(self) -> &'static str {
+        match self {
+            Ipv6AddressMode::Slaac => "0",
+            Ipv6AddressMode::StatelessDhcpv6 => "1",
+            Ipv6AddressMode::StatefulDhcpv6 => "2",
+        }
+    }
+}
+
+impl<'de> Deserialize<'de> for Ipv6AddressMode {
+    fn deserialize<D>(d: D) -> Result<Self, D::Error>
+    where
+        D: Deserializer<'de>,
+    {
+        match u8::deserialize(d)? {
+            0 => Ok(Self::Slaac),
+            1 => Ok(Self::StatelessDhcpv6),
+            2 => Ok(Self::StatefulDhcpv6),
+            _ => Err(D::Error::custom("IPv6 address mode not in range 0..=2")),
+        }
+    }
+}
+
+impl Display for Ipv6AddressMode {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.write_str(match self {
+            Ipv6AddressMode::Slaac => "SLAAC",
+            Ipv6AddressMode::StatelessDhcpv6 => "Stateless DHCPv6",
+            Ipv6AddressMode::StatefulDhcpv6 => "Stateful DHCPv6",
+        })
+    }
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct Ipv6Prefix {
+    pub address: Ipv6Addr,
+    pub length: u8,
+}
+
+impl FromStr for Ipv6Prefix {
+    type Err = crate::Error;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        let invalid = || crate::Error::InvalidIpv6Prefix(s.to_string());
+        let (address, length) = s.split_once('/').ok_or_else(invalid)?;
+        let address = address.parse().map_err(|_| invalid())?;
+        let length = length
+            .parse()
+            .ok()
+            .filter(|l| *l <= 128)
+            .ok_or_else(invalid)?;
+        Ok(Self { address, length })
+    }
+}
+
+impl Display for Ipv6Prefix {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(f, "{}/{}", self.address, self.length)
+    }
+}
+
+#[derive(Deserialize, Debug)]
+pub struct DhcpReservations {
+    #[serde(rename = "Reservation")]
+    #[serde(default = "Vec::default")]
+    pub entries: Vec<DhcpReservation>,
+}
+
+#[derive(Deserialize, Debug, Clone)]
+pub struct DhcpReservation {
+    #[serde(rename = "MACAddr")]
+    pub mac: MacAddress,
+    #[serde(rename = "IPAddr")]
+    pub ip: Ipv4Addr,
+}
+
+#[derive(Deserialize, Debug)]
+pub(crate) struct PingStatus {
+    #[serde(rename = "Status")]
+    #[serde(deserialize_with = "bool_from_int")]
+    pub finished: bool,
+    #[serde(rename = "probe")]
+    #[serde(default = "Vec::default")]
+    pub probes: Vec<PingProbe>,
+}
+
+#[derive(Deserialize, Debug)]
+pub(crate) struct PingProbe {
+    #[serde(deserialize_with = "optional_rtt")]
+    pub rtt: Option<Duration>,
+}
+
+#[derive(Debug, Clone)]
+pub struct PingResult {
+    /// Round-trip time of every probe, `None` if no reply was received
+    pub probes: Vec<Option<Duration>>,
+    /// Percentage of the requested probes that didn't receive a reply, 100 if the router couldn't send any
+    pub loss_percent: f32,
+    /// Minimum round-trip time, `None` if no replies were received
+    pub min: Option<Duration>,
+    /// Average round-trip time, `None` if no replies were received
+    pub avg: Option<Duration>,
+    /// Maximum round-trip time, `None` if no replies were received
+    pub max: Option<Duration>,
+}
+
+impl PingResult {
+    pub(crate) fn new(status: PingStatus, count: u8) -> Self {
+        let probes: Vec<_> = status.probes.into_iter().map(|p| p.rtt).collect();
+        let replies: Vec<Duration> = probes.iter().flatten().copied().collect();
+        // probes that were never sent (e.g. because the target couldn't be resolved) count as lost
+        let sent = probes.len().max(count.into());
+        #[allow(clippy::cast_precision_loss)]
+        let loss_percent = (sent - replies.len()) as f32 * 100.0 / sent as f32;
+        let avg = (!replies.is_empty()).then(|| {
+            #[allow(clippy::cast_possible_truncation)]
+            let count = replies.len() as u32;
+            replies.iter().sum::<Duration>() / count
+        });
+        Self {
+            loss_percent,
+            min: replies.iter().min().copied(),
+            avg,
+            max: replies.iter().max().copied(),
+            probes,
+        }
+    }
+}
+
+#[derive(Deserialize, Debug)]
+pub(crate) struct TracerouteStatus {
+    #[serde(rename = "Status")]
+    #[serde(deserialize_with = "bool_from_int")]
+    pub finished: bool,
+    #[serde(rename = "hop")]
+    #[serde(default = "Vec::default")]
+    pub hops: Vec<TracerouteHop>,
+}
+
+#[derive(Deserialize, Debug, Clone)]
+#[serde(from = "RawTracerouteHop")]
+pub struct TracerouteHop {
+    pub number: u8,
+    /// Address of the host that replied, `None` if none of the probes received a reply
+    pub address: Option<IpAddr>,
+    /// Round-trip time of every probe, `None` if no reply was received
+    pub probes: Vec<Option<Duration>>,
+}
+
+impl TracerouteHop {
+    /// Number of probes that didn't receive a reply
+    #[must_use]
+    pub fn timeouts(&self) -> usize {
+        self.probes.iter().filter(|p| p.is_none()).count()
     }
 }
 
 #[derive(Deserialize)]
-struct List<T> {
-    #[serde(rename = "$value")]
+struct RawTracerouteHop {
+    num: u8,
+    #[serde(deserialize_with = "optional_ip")]
+    addr: Option<IpAddr>,
+    #[serde(deserialize_with = "optional_rtt")]
+    rtt1: Option<Duration>,
+    #[serde(deserialize_with = "optional_rtt")]
+    rtt2: Option<Duration>,
+    #[serde(deserialize_with = "optional_rtt")]
+    rtt3: Option<Duration>,
+}
+
+impl From<RawTracerouteHop> for TracerouteHop {
+    fn from(h: RawTracerouteHop) -> Self {
+        Self {
+            number: h.num,
+            address: h.addr,
+            probes: vec![h.rtt1, h.rtt2, h.rtt3],
+        }
+    }
+}
+
+#[derive(Deserialize, Debug)]
+pub struct DownstreamChannels {
+    #[serde(rename = "ds_num")]
+    pub count: u32,
+    // unlike the client lists in LanUserTable, the channels aren't wrapped in a container element, the
+    // <downstream> elements follow each other directly inside the root, so unwrap_xml_list doesn't apply
+    #[serde(rename = "downstream")]
     #[serde(default = "Vec::default")]
-    elems: Vec<T>,
+    pub channels: Vec<DownstreamChannel>,
 }
 
-fn unwrap_xml_list<'de, D, T>(deserializer: D) -> Result<Vec<T>, D::Error>
-where
-    D: Deserializer<'de>,
-    T: Deserialize<'de>,
-{
-    Ok(List::deserialize(deserializer)?.elems)
+#[derive(Deserialize, Debug)]
+pub struct DownstreamChannel {
+    #[serde(rename = "chid")]
+    pub id: u32,
+    /// Center frequency in Hz
+    #[serde(rename = "freq")]
+    pub frequency: u32,
+    /// Power level in dBmV
+    #[serde(rename = "pow")]
+    pub power: f32,
+    /// Signal to noise ratio in dB
+    pub snr: f32,
+    /// Receive modulation error ratio in dB
+    #[serde(rename = "RxMER")]
+    pub rx_mer: f32,
+    #[serde(rename = "mod")]
+    pub modulation: Modulation,
+    /// Number of codewords with errors corrected by Reed-Solomon FEC
+    #[serde(rename = "PreRs")]
+    pub pre_rs_errors: u64,
+    /// Number of codewords with errors that could not be corrected by Reed-Solomon FEC
+    #[serde(rename = "PostRs")]
+    pub post_rs_errors: u64,
+    #[serde(rename = "IsQamLocked")]
+    #[serde(deserialize_with = "bool_from_int")]
+    pub qam_locked: bool,
+    #[serde(rename = "IsFECLocked")]
+    #[serde(deserialize_with = "bool_from_int")]
+    pub fec_locked: bool,
+    #[serde(rename = "IsMpegLocked")]
+    #[serde(deserialize_with = "bool_from_int")]
+    pub mpeg_locked: bool,
 }
 
-fn deserialize_lease_time<'de, D>(d: D) -> Result<Duration, D::Error>
-where
-    D: Deserializer<'de>,
-{
-    let mut fields = <&str as Deserialize>::deserialize(d)?.split(':').map(|f| {
-        f.parse::<u32>()
-            .map_err(|e| D::Error::custom(e.to_string()))
-    });
-    let days = fields
-        .next()
-        .ok_or(D::Error::custom("no days field in lease time"))??;
-    let hours = fields
-        .next()
-        .ok_or(D::Error::custom("no hours field in lease time"))??;
-    let mins = fields
-        .next()
-        .ok_or(D::Error::custom("no mins field in lease time"))??;
-    let secs = fields
-        .next()
-        .ok_or(D::Error::custom("no secs field in lease time"))??;
-    let secs_total = days * 86400 + hours * 3600 + mins * 60 + secs;
-    Ok(Duration::from_secs(u64::from(secs_total)))
+#[derive(Deserialize, Debug)]
+pub struct UpstreamChannels {
+    #[serde(rename = "us_num")]
+    pub count: u32,
+    // flat list, like in DownstreamChannels
+    #[serde(rename = "upstream")]
+    #[serde(default = "Vec::default")]
+    pub channels: Vec<UpstreamChannel>,
+}
+
+#[derive(Deserialize, Debug)]
+pub struct UpstreamChannel {
+    #[serde(rename = "usid")]
+    pub id: u32,
+    /// Center frequency in Hz
+    #[serde(rename = "freq")]
+    pub frequency: u32,
+    /// Symbol rate in MSym/s
+    #[serde(rename = "srate")]
+    pub symbol_rate: f32,
+    /// Transmit power level in dBmV
+    pub power: f32,
+    #[serde(rename = "mod")]
+    pub modulation: Modulation,
+    #[serde(rename = "channeltype")]
+    pub channel_type: String,
+    #[serde(rename = "t1Timeouts")]
+    pub t1_timeouts: u32,
+    #[serde(rename = "t2Timeouts")]
+    pub t2_timeouts: u32,
+    #[serde(rename = "t3Timeouts")]
+    pub t3_timeouts: u32,
+    #[serde(rename = "t4Timeouts")]
+    pub t4_timeouts: u32,
+}
+
+#[derive(Debug)]
+pub struct SystemInfo {
+    pub hardware_version: String,
+    pub firmware_version: String,
+    pub serial_number: String,
+    pub cm_mac: MacAddress,
+    pub uptime: Duration,
+    pub docsis_mode: String,
+    pub provisioning_status: String,
+    pub bpi_enabled: bool,
+    pub network_access: bool,
+}
+
+impl SystemInfo {
+    pub(crate) fn new(global: GlobalSettings, info: CmSystemInfo, status: CmStatus) -> Self {
+        Self {
+            hardware_version: info.hardware_version,
+            firmware_version: global.sw_version,
+            serial_number: info.serial_number,
+            cm_mac: info.mac,
+            uptime: info.uptime,
+            docsis_mode: info.docsis_mode,
+            provisioning_status: status.provisioning_status,
+            bpi_enabled: status.bpi_enabled,
+            network_access: info.network_access,
+        }
+    }
+}
+
+#[derive(Deserialize, Debug)]
+pub(crate) struct GlobalSettings {
+    #[serde(rename = "SwVersion")]
+    pub sw_version: String,
+}
+
+/// The operating mode, read from the global settings separately so that firmware versions which don't report it can still return the rest of them
+#[derive(Deserialize, Debug)]
+pub(crate) struct GlobalOperatingMode {
+    #[serde(rename = "GwOperMode")]
+    pub operating_mode: OperatingMode,
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum OperatingMode {
+    /// The Connect Box routes traffic between the LAN and the WAN, with all features like Wi-Fi, DHCP and port forwarding available
+    Router,
+    /// The Connect Box acts as a plain cable modem and passes the public address through to a single device connected to it.
+    /// The web interface is then only available at [`BRIDGE_MODE_ADDRESS`](crate::BRIDGE_MODE_ADDRESS). Its status can be monitored without logging in through [`BridgeModeClient`](crate::BridgeModeClient),
+    /// and a [`ConnectBox`](crate::ConnectBox) logged in at that address can switch back to router mode with [`set_operating_mode`](crate::ConnectBox::set_operating_mode).
+    Bridge,
+}
+
+impl OperatingMode {
+    pub(crate) fn id_str(self) -> &'static str {
+        match self {
+            OperatingMode::Router => "1",
+            OperatingMode::Bridge => "2",
+        }
+    }
+}
+
+impl<'de> Deserialize<'de> for OperatingMode {
+    fn deserialize<D>(d: D) -> Result<Self, D::Error>
+    where
+        D: Deserializer<'de>,
+    {
+        match u8::deserialize(d)? {
+            1 => Ok(Self::Router),
+            2 => Ok(Self::Bridge),
+            _ => Err(D::Error::custom("operating mode not in range 1..=2")),
+        }
+    }
+}
+
+impl Display for OperatingMode {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.write_str(match self {
+            OperatingMode::Router => "Router",
+            OperatingMode::Bridge => "Bridge",
+        })
+    }
+}
+
+#[derive(Deserialize, Debug)]
+pub(crate) struct CmSystemInfo {
+    #[serde(rename = "cm_docsis_mode")]
+    pub docsis_mode: String,
+    #[serde(rename = "cm_hardware_version")]
+    pub hardware_version: String,
+    #[serde(rename = "cm_mac_addr")]
+    pub mac: MacAddress,
+    #[serde(rename = "cm_serial_number")]
+    pub serial_number: String,
+    #[serde(rename = "cm_system_uptime")]
+    #[serde(deserialize_with = "deserialize_uptime")]
+    pub uptime: Duration,
+    #[serde(rename = "cm_network_access")]
+    #[serde(deserialize_with = "bool_from_access")]
+    pub network_access: bool,
+}
+
+#[derive(Deserialize, Debug)]
+pub(crate) struct CmStatus {
+    #[serde(rename = "provisioning_st")]
+    pub provisioning_status: String,
+    #[serde(rename = "BPIEnable")]
+    #[serde(deserialize_with = "bool_from_int")]
+    pub bpi_enabled: bool,
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub struct MacAddress(pub [u8; 6]);
+
+impl FromStr for MacAddress {
+    type Err = crate::Error;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        let mut octets = [0; 6];
+        let mut fields = s.split([':', '-']);
+        for octet in &mut octets {
+            *octet = fields
+                .next()
+                .filter(|f| f.len() == 2 && f.bytes().all(|b| b.is_ascii_hexdigit()))
+                .and_then(|f| u8::from_str_radix(f, 16).ok())
+                .ok_or_else(|| crate::Error::InvalidMacAddress(s.to_string()))?;
+        }
+        if fields.next().is_some() {
+            return Err(crate::Error::InvalidMacAddress(s.to_string()));
+        }
+        Ok(Self(octets))
+    }
+}
+
+impl<'de> Deserialize<'de> for MacAddress {
+    fn deserialize<D>(d: D) -> Result<Self, D::Error>
+    where
+        D: Deserializer<'de>,
+    {
+        String::deserialize(d)?
+            .parse()
+            .map_err(|e: crate::Error| D::Error::custom(e.to_string()))
+    }
+}
+
+impl Display for MacAddress {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        let [a, b, c, d, e, g] = self.0;
+        write!(f, "{a:02X}:{b:02X}:{c:02X}:{d:02X}:{e:02X}:{g:02X}")
+    }
+}
+
+#[derive(Deserialize, Debug, Clone)]
+#[serde(from = "WirelessBasic")]
+pub struct WirelessSettings {
+    pub band_2g: RadioSettings,
+    pub band_5g: RadioSettings,
+}
+
+impl WirelessSettings {
+    #[must_use]
+    pub fn band(&self, band: WifiBand) -> &RadioSettings {
+        match band {
+            WifiBand::Band2_4GHz => &self.band_2g,
+            WifiBand::Band5GHz => &self.band_5g,
+        }
+    }
+
+    pub fn band_mut(&mut self, band: WifiBand) -> &mut RadioSettings {
+        match band {
+            WifiBand::Band2_4GHz => &mut self.band_2g,
+            WifiBand::Band5GHz => &mut self.band_5g,
+        }
+    }
+}
+
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct RadioSettings {
+    pub enabled: bool,
+    pub ssid: String,
+    pub broadcast_ssid: bool,
+    pub security: WifiSecurity,
+    pub passphrase: String,
+    /// Configured channel, `None` if the channel is selected automatically
+    pub channel: Option<u8>,
+    /// Channel the radio is currently operating on
+    pub current_channel: u8,
+    pub channel_width: ChannelWidth,
+    pub mode: WifiMode,
+    pub transmit_power: TransmitPower,
+}
+
+/// Radio parameters applied by [`ConnectBox::tune_radio`](crate::ConnectBox::tune_radio)
+#[derive(Debug, Clone, Copy)]
+pub struct RadioTuning {
+    /// Channel to use, `None` to select it automatically
+    pub channel: Option<u8>,
+    pub channel_width: ChannelWidth,
+    pub mode: WifiMode,
+    pub transmit_power: TransmitPower,
+}
+
+#[derive(Deserialize, Debug)]
+struct WirelessBasic {
+    #[serde(rename = "BssEnable2g")]
+    #[serde(deserialize_with = "bool_from_int")]
+    enabled_2g: bool,
+    #[serde(rename = "BssEnable5g")]
+    #[serde(deserialize_with = "bool_from_int")]
+    enabled_5g: bool,
+    #[serde(rename = "SSID2G")]
+    ssid_2g: String,
+    #[serde(rename = "SSID5G")]
+    ssid_5g: String,
+    #[serde(rename = "HideNetwork2G")]
+    #[serde(deserialize_with = "bool_from_int")]
+    hidden_2g: bool,
+    #[serde(rename = "HideNetwork5G")]
+    #[serde(deserialize_with = "bool_from_int")]
+    hidden_5g: bool,
+    #[serde(rename = "SecurityMode2g")]
+    security_2g: WifiSecurity,
+    #[serde(rename = "SecurityMode5g")]
+    security_5g: WifiSecurity,
+    #[serde(rename = "PreSharedKey2g")]
+    #[serde(default)]
+    passphrase_2g: String,
+    #[serde(rename = "PreSharedKey5g")]
+    #[serde(default)]
+    passphrase_5g: String,
+    #[serde(rename = "ChannelSetting2G")]
+    channel_2g: u8,
+    #[serde(rename = "ChannelSetting5G")]
+    channel_5g: u8,
+    #[serde(rename = "CurrentChannel2G")]
+    current_channel_2g: u8,
+    #[serde(rename = "CurrentChannel5G")]
+    current_channel_5g: u8,
+    #[serde(rename = "BandWidth2G")]
+    channel_width_2g: ChannelWidth,
+    #[serde(rename = "BandWidth5G")]
+    channel_width_5g: ChannelWidth,
+    #[serde(rename = "TransmissionMode2g")]
+    mode_2g: WifiMode,
+    #[serde(rename = "TransmissionMode5g")]
+    mode_5g: WifiMode,
+    #[serde(rename = "TransmitPower2g")]
+    transmit_power_2g: TransmitPower,
+    #[serde(rename = "TransmitPower5g")]
+    transmit_power_5g: TransmitPower,
+}
+
+impl From<WirelessBasic> for WirelessSettings {
+    fn from(w: WirelessBasic) -> Self {
+        Self {
+            band_2g: RadioSettings {
+                enabled: w.enabled_2g,
+                ssid: w.ssid_2g,
+                broadcast_ssid: !w.hidden_2g,
+                security: w.security_2g,
+                passphrase: w.passphrase_2g,
+                channel: (w.channel_2g != 0).then_some(w.channel_2g),
+                current_channel: w.current_channel_2g,
+                channel_width: w.channel_width_2g,
+                mode: w.mode_2g,
+                transmit_power: w.transmit_power_2g,
+            },
+            band_5g: RadioSettings {
+                enabled: w.enabled_5g,
+                ssid: w.ssid_5g,
+                broadcast_ssid: !w.hidden_5g,
+                security: w.security_5g,
+                passphrase: w.passphrase_5g,
+                channel: (w.channel_5g != 0).then_some(w.channel_5g),
+                current_channel: w.current_channel_5g,
+                channel_width: w.channel_width_5g,
+                mode: w.mode_5g,
+                transmit_power: w.transmit_power_5g,
+            },
+        }
+    }
+}
+
+#[derive(Deserialize, Debug)]
+pub struct GuestNetworks {
+    #[serde(rename = "Interface")]
+    #[serde(default = "Vec::default")]
+    pub networks: Vec<GuestNetwork>,
+}
+
+#[derive(Deserialize, Debug, Clone)]
+pub struct GuestNetwork {
+    #[serde(rename = "Index")]
+    pub index: u32,
+    #[serde(rename = "Band")]
+    pub band: WifiBand,
+    #[serde(rename = "BssEnable")]
+    #[serde(deserialize_with = "bool_from_int")]
+    pub enabled: bool,
+    #[serde(rename = "GuestSSID")]
+    pub ssid: String,
+    #[serde(rename = "SecurityMode")]
+    pub security: WifiSecurity,
+    #[serde(rename = "PreSharedKey")]
+    #[serde(default)]
+    pub passphrase: String,
+    /// Whether clients of the guest network are isolated from each other and from the LAN
+    #[serde(rename = "Isolation")]
+    #[serde(deserialize_with = "bool_from_int")]
+    pub isolation: bool,
+}
+
+#[derive(Deserialize, Debug)]
+pub struct WpsStatus {
+    #[serde(rename = "WpsEnable")]
+    #[serde(deserialize_with = "bool_from_int")]
+    pub enabled: bool,
+    #[serde(rename = "WpsState")]
+    pub state: WpsState,
+    /// PIN of the router itself, used when pairing from the client side
+    #[serde(rename = "WpsPin")]
+    pub router_pin: String,
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum WpsState {
+    Idle,
+    InProgress,
+    Success,
+    Failed,
+    TimedOut,
+}
+
+impl<'de> Deserialize<'de> for WpsState {
+    fn deserialize<D>(d: D) -> Result<Self, D::Error>
+    where
+        D: Deserializer<'de>,
+    {
+        match u8::deserialize(d)? {
+            0 => Ok(Self::Idle),
+            1 => Ok(Self::InProgress),
+            2 => Ok(Self::Success),
+            3 => Ok(Self::Failed),
+            4 => Ok(Self::TimedOut),
+            _ => Err(D::Error::custom("WPS state not in range 0..=4")),
+        }
+    }
+}
+
+impl Display for WpsState {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.write_str(match self {
+            WpsState::Idle => "Idle",
+            WpsState::InProgress => "In progress",
+            WpsState::Success => "Success",
+            WpsState::Failed => "Failed",
+            WpsState::TimedOut => "Timed out",
+        })
+    }
+}
+
+#[derive(Deserialize, Debug)]
+pub(crate) struct WifiScan {
+    #[serde(rename = "Status")]
+    #[serde(deserialize_with = "bool_from_int")]
+    pub finished: bool,
+    #[serde(rename = "AP")]
+    #[serde(default = "Vec::default")]
+    pub access_points: Vec<AccessPoint>,
+}
+
+#[derive(Deserialize, Debug, Clone)]
+pub struct AccessPoint {
+    #[serde(rename = "SSID")]
+    pub ssid: String,
+    #[serde(rename = "BSSID")]
+    pub bssid: MacAddress,
+    #[serde(rename = "Channel")]
+    pub channel: u8,
+    /// Signal strength in dBm
+    #[serde(rename = "Signal")]
+    pub signal: i32,
+    #[serde(rename = "Security")]
+    pub security: AccessPointSecurity,
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum WifiBand {
+    Band2_4GHz,
+    Band5GHz,
+}
+
+impl WifiBand {
+    /// Channels that can be configured in this band
+    #[must_use]
+    pub fn channels(self) -> &'static [u8] {
+        match self {
+            WifiBand::Band2_4GHz => &[1, 2, 3, 4, 5, 6, 7, 8, 9, 10, 11, 12, 13],
+            WifiBand::Band5GHz => &[
+                36, 40, 44, 48, 52, 56, 60, 64, 100, 104, 108, 112, 116, 120, 124, 128, 132, 136,
+                140,
+            ],
+        }
+    }
+
+    pub(crate) fn id_str(self) -> &'static str {
+        match self {
+            WifiBand::Band2_4GHz => "1",
+            WifiBand::Band5GHz => "2",
+        }
+    }
+
+    #[must_use]
+    pub fn new(s: &str) -> Option<Self> {
+        match s.to_lowercase().as_str() {
+            "2.4" | "2.4ghz" | "2g" => Some(Self::Band2_4GHz),
+            "5" | "5ghz" | "5g" => Some(Self::Band5GHz),
+            _ => None,
+        }
+    }
+}
+
+impl<'de> Deserialize<'de> for WifiBand {
+    fn deserialize<D>(d: D) -> Result<Self, D::Error>
+    where
+        D: Deserializer<'de>,
+    {
+        match u8::deserialize(d)? {
+            1 => Ok(Self::Band2_4GHz),
+            2 => Ok(Self::Band5GHz),
+            _ => Err(D::Error::custom("band not in range 1..=2")),
+        }
+    }
+}
+
+impl Display for WifiBand {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.write_str(match self {
+            WifiBand::Band2_4GHz => "2.4 GHz",
+            WifiBand::Band5GHz => "5 GHz",
+        })
+    }
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum WifiSecurity {
+    Open,
+    Wpa2Psk,
+    WpaWpa2Psk,
+    /// A security mode not supported by this library, with the id used by the router
+    Other(u8),
+}
+
+impl WifiSecurity {
+    pub(crate) fn id_str(self) -> Cow<'static, str> {
+        match self {
+            WifiSecurity::Open => "0".into(),
+            WifiSecurity::Wpa2Psk => "4".into(),
+            WifiSecurity::WpaWpa2Psk => "8".into(),
+            WifiSecurity::Other(id) => id.to_string().into(),
+        }
+    }
+
+    #[must_use]
+    pub fn new(s: &str) -> Option<Self> {
+        match s.to_lowercase().as_str() {
+            "open" => Some(Self::Open),
+            "wpa2" | "wpa2-psk" => Some(Self::Wpa2Psk),
+            "wpa/wpa2" | "wpa-wpa2" | "wpa/wpa2-psk" => Some(Self::WpaWpa2Psk),
+            _ => None,
+        }
+    }
+}
+
+impl<'de> Deserialize<'de> for WifiSecurity {
+    fn deserialize<D>(d: D) -> Result<Self, D::Error>
+    where
+        D: Deserializer<'de>,
+    {
+        Ok(match u8::deserialize(d)? {
+            0 => Self::Open,
+            4 => Self::Wpa2Psk,
+            8 => Self::WpaWpa2Psk,
+            other => Self::Other(other),
+        })
+    }
+}
+
+impl Display for WifiSecurity {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            WifiSecurity::Open => f.write_str("Open"),
+            WifiSecurity::Wpa2Psk => f.write_str("WPA2-PSK"),
+            WifiSecurity::WpaWpa2Psk => f.write_str("WPA/WPA2-PSK"),
+            WifiSecurity::Other(id) => write!(f, "Unknown ({id})"),
+        }
+    }
+}
+
+/// Security mode of a neighbouring network found by a Wi-Fi scan
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum AccessPointSecurity {
+    Open,
+    Wep,
+    Wpa,
+    Wpa2,
+    WpaWpa2,
+    Wpa3,
+    Wpa2Wpa3,
+    /// A security mode not recognized by this library, as reported by the router
+    Other(String),
+}
+
+impl<'de> Deserialize<'de> for AccessPointSecurity {
+    fn deserialize<D>(d: D) -> Result<Self, D::Error>
+    where
+        D: Deserializer<'de>,
+    {
+        let s = String::deserialize(d)?;
+        let normalized = s.to_lowercase().replace("-psk", "").replace("-sae", "");
+        Ok(match normalized.trim() {
+            "" | "none" | "open" => Self::Open,
+            "wep" => Self::Wep,
+            "wpa" => Self::Wpa,
+            "wpa2" => Self::Wpa2,
+            "wpa/wpa2" | "wpa-wpa2" => Self::WpaWpa2,
+            "wpa3" => Self::Wpa3,
+            "wpa2/wpa3" | "wpa2-wpa3" => Self::Wpa2Wpa3,
+            _ => Self::Other(s),
+        })
+    }
+}
+
+impl Display for AccessPointSecurity {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.write_str(match self {
+            AccessPointSecurity::Open => "Open",
+            AccessPointSecurity::Wep => "WEP",
+            AccessPointSecurity::Wpa => "WPA",
+            AccessPointSecurity::Wpa2 => "WPA2",
+            AccessPointSecurity::WpaWpa2 => "WPA/WPA2",
+            AccessPointSecurity::Wpa3 => "WPA3",
+            AccessPointSecurity::Wpa2Wpa3 => "WPA2/WPA3",
+            AccessPointSecurity::Other(s) => s,
+        })
+    }
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum ChannelWidth {
+    Mhz20,
+    Mhz40,
+    Mhz80,
+}
+
+impl ChannelWidth {
+    pub(crate) fn id_str(self) -> &'static str {
+        match self {
+            ChannelWidth::Mhz20 => "1",
+            ChannelWidth::Mhz40 => "2",
+            ChannelWidth::Mhz80 => "3",
+        }
+    }
+}
+
+impl<'de> Deserialize<'de> for ChannelWidth {
+    fn deserialize<D>(d: D) -> Result<Self, D::Error>
+    where
+        D: Deserializer<'de>,
+    {
+        match u8::deserialize(d)? {
+            1 => Ok(Self::Mhz20),
+            2 => Ok(Self::Mhz40),
+            3 => Ok(Self::Mhz80),
+            _ => Err(D::Error::custom("channel width not in range 1..=3")),
+        }
+    }
+}
+
+impl Display for ChannelWidth {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.write_str(match self {
+            ChannelWidth::Mhz20 => "20 MHz",
+            ChannelWidth::Mhz40 => "40 MHz",
+            ChannelWidth::Mhz80 => "80 MHz",
+        })
+    }
+}
+
+/// The 802.11 standards a radio operates in
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum WifiMode {
+    /// 802.11b/g/n mixed, 2.4 GHz only
+    Bgn,
+    /// 802.11g/n mixed, 2.4 GHz only
+    Gn,
+    /// 802.11n only
+    N,
+    /// 802.11a/n/ac mixed, 5 GHz only
+    Anac,
+    /// 802.11n/ac mixed, 5 GHz only
+    Nac,
+    /// 802.11ac only, 5 GHz only
+    Ac,
+}
+
+impl WifiMode {
+    pub(crate) fn id_str(self) -> &'static str {
+        match self {
+            WifiMode::Bgn => "1",
+            WifiMode::Gn => "2",
+            WifiMode::N => "3",
+            WifiMode::Anac => "4",
+            WifiMode::Nac => "5",
+            WifiMode::Ac => "6",
+        }
+    }
+}
+
+impl<'de> Deserialize<'de> for WifiMode {
+    fn deserialize<D>(d: D) -> Result<Self, D::Error>
+    where
+        D: Deserializer<'de>,
+    {
+        match u8::deserialize(d)? {
+            1 => Ok(Self::Bgn),
+            2 => Ok(Self::Gn),
+            3 => Ok(Self::N),
+            4 => Ok(Self::Anac),
+            5 => Ok(Self::Nac),
+            6 => Ok(Self::Ac),
+            _ => Err(D::Error::custom("802.11 mode not in range 1..=6")),
+        }
+    }
+}
+
+impl Display for WifiMode {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.write_str(match self {
+            WifiMode::Bgn => "802.11b/g/n",
+            WifiMode::Gn => "802.11g/n",
+            WifiMode::N => "802.11n",
+            WifiMode::Anac => "802.11a/n/ac",
+            WifiMode::Nac => "802.11n/ac",
+            WifiMode::Ac => "802.11ac",
+        })
+    }
+}
+
+/// Transmit power as a percentage of the maximum
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum TransmitPower {
+    Percent25,
+    Percent50,
+    Percent75,
+    Percent100,
+}
+
+impl TransmitPower {
+    pub(crate) fn id_str(self) -> &'static str {
+        match self {
+            TransmitPower::Percent25 => "1",
+            TransmitPower::Percent50 => "2",
+            TransmitPower::Percent75 => "3",
+            TransmitPower::Percent100 => "4",
+        }
+    }
+}
+
+impl<'de> Deserialize<'de> for TransmitPower {
+    fn deserialize<D>(d: D) -> Result<Self, D::Error>
+    where
+        D: Deserializer<'de>,
+    {
+        match u8::deserialize(d)? {
+            1 => Ok(Self::Percent25),
+            2 => Ok(Self::Percent50),
+            3 => Ok(Self::Percent75),
+            4 => Ok(Self::Percent100),
+            _ => Err(D::Error::custom("transmit power not in range 1..=4")),
+        }
+    }
+}
+
+impl Display for TransmitPower {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.write_str(match self {
+            TransmitPower::Percent25 => "25%",
+            TransmitPower::Percent50 => "50%",
+            TransmitPower::Percent75 => "75%",
+            TransmitPower::Percent100 => "100%",
+        })
+    }
+}
+
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum Modulation {
+    Qpsk,
+    Qam16,
+    Qam32,
+    Qam64,
+    Qam128,
+    Qam256,
+    Qam1024,
+    Other(String),
+}
+
+impl<'de> Deserialize<'de> for Modulation {
+    fn deserialize<D>(d: D) -> Result<Self, D::Error>
+    where
+        D: Deserializer<'de>,
+    {
+        let s = String::deserialize(d)?;
+        Ok(match s.to_lowercase().as_str() {
+            "qpsk" => Self::Qpsk,
+            "16qam" => Self::Qam16,
+            "32qam" => Self::Qam32,
+            "64qam" => Self::Qam64,
+            "128qam" => Self::Qam128,
+            "256qam" => Self::Qam256,
+            "1024qam" => Self::Qam1024,
+            _ => Self::Other(s),
+        })
+    }
+}
+
+impl Display for Modulation {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.write_str(match self {
+            Modulation::Qpsk => "QPSK",
+            Modulation::Qam16 => "16-QAM",
+            Modulation::Qam32 => "32-QAM",
+            Modulation::Qam64 => "64-QAM",
+            Modulation::Qam128 => "128-QAM",
+            Modulation::Qam256 => "256-QAM",
+            Modulation::Qam1024 => "1024-QAM",
+            Modulation::Other(s) => s,
+        })
+    }
+}
+
+fn bool_from_int<'de, D>(deserializer: D) -> Result<bool, D::Error>
+where
+    D: Deserializer<'de>,
+{
+    match u8::deserialize(deserializer)? {
+        0 => Ok(false),
+        1 => Ok(true),
+        other => Err(de::Error::invalid_value(
+            Unexpected::Unsigned(u64::from(other)),
+            &"zero or one",
+        )),
+    }
+}
+
+fn duration_from_secs<'de, D>(deserializer: D) -> Result<Duration, D::Error>
+where
+    D: Deserializer<'de>,
+{
+    Ok(Duration::from_secs(u64::deserialize(deserializer)?))
+}
+
+/// Deserialize an IPv4 address that may be empty or all zeros when it's not set
+fn optional_ipv4<'de, D>(deserializer: D) -> Result<Option<Ipv4Addr>, D::Error>
+where
+    D: Deserializer<'de>,
+{
+    let s = String::deserialize(deserializer)?;
+    if s.is_empty() {
+        return Ok(None);
+    }
+    let addr: Ipv4Addr = s.parse().map_err(|e| D::Error::custom(format!("{e}")))?;
+    Ok((!addr.is_unspecified()).then_some(addr))
+}
+
+fn optional_ipv6<'de, D>(deserializer: D) -> Result<Option<Ipv6Addr>, D::Error>
+where
+    D: Deserializer<'de>,
+{
+    let s = String::deserialize(deserializer)?;
+    if s.is_empty() {
+        return Ok(None);
+    }
+    let addr: Ipv6Addr = s.parse().map_err(|e| D::Error::custom(format!("{e}")))?;
+    Ok((!addr.is_unspecified()).then_some(addr))
+}
+
+fn optional_ipv6_prefix<'de, D>(deserializer: D) -> Result<Option<Ipv6Prefix>, D::Error>
+where
+    D: Deserializer<'de>,
+{
+    let s = String::deserialize(deserializer)?;
+    if s.is_empty() {
+        return Ok(None);
+    }
+    s.parse()
+        .map(Some)
+        .map_err(|e: crate::Error| D::Error::custom(e.to_string()))
+}
+
+/// Deserialize an IP address that is empty or an asterisk when unknown
+fn optional_ip<'de, D>(deserializer: D) -> Result<Option<IpAddr>, D::Error>
+where
+    D: Deserializer<'de>,
+{
+    let s = String::deserialize(deserializer)?;
+    if s.is_empty() || s == "*" {
+        return Ok(None);
+    }
+    s.parse()
+        .map(Some)
+        .map_err(|e| D::Error::custom(format!("{e}")))
+}
+
+/// Deserialize a round-trip time in milliseconds, which is empty or negative for lost probes
+fn optional_rtt<'de, D>(deserializer: D) -> Result<Option<Duration>, D::Error>
+where
+    D: Deserializer<'de>,
+{
+    let s = String::deserialize(deserializer)?;
+    if s.is_empty() || s == "*" {
+        return Ok(None);
+    }
+    let ms: f64 = s.parse().map_err(|e| D::Error::custom(format!("{e}")))?;
+    Ok((ms >= 0.0).then(|| Duration::from_secs_f64(ms / 1000.0)))
+}
+
+fn bool_from_access<'de, D>(deserializer: D) -> Result<bool, D::Error>
+where
+    D: Deserializer<'de>,
+{
+    match String::deserialize(deserializer)?.as_str() {
+        "Allowed" => Ok(true),
+        "Denied" => Ok(false),
+        other => Err(de::Error::invalid_value(
+            Unexpected::Str(other),
+            &"Allowed or Denied",
+        )),
+    }
+}
+
+#[derive(Deserialize)]
+struct List<T> {
+    #[serde(rename = "$value")]
+    #[serde(default = "Vec::default")]
+    elems: Vec<T>,
+}
+
+fn unwrap_xml_list<'de, D, T>(deserializer: D) -> Result<Vec<T>, D::Error>
+where
+    D: Deserializer<'de>,
+    T: Deserialize<'de>,
+{
+    Ok(List::deserialize(deserializer)?.elems)
+}
+
+fn optional_lease_time<'de, D>(d: D) -> Result<Option<Duration>, D::Error>
+where
+    D: Deserializer<'de>,
+{
+    let s = <&str as Deserialize>::deserialize(d)?;
+    if s.is_empty() {
+        return Ok(None);
+    }
+    deserialize_lease_time(de::value::BorrowedStrDeserializer::new(s)).map(Some)
+}
+
+fn deserialize_lease_time<'de, D>(d: D) -> Result<Duration, D::Error>
+where
+    D: Deserializer<'de>,
+{
+    let mut fields = <&str as Deserialize>::deserialize(d)?.split(':').map(|f| {
+        f.parse::<u32>()
+            .map_err(|e| D::Error::custom(e.to_string()))
+    });
+    let days = fields
+        .next()
+        .ok_or(D::Error::custom("no days field in lease time"))??;
+    let hours = fields
+        .next()
+        .ok_or(D::Error::custom("no hours field in lease time"))??;
+    let mins = fields
+        .next()
+        .ok_or(D::Error::custom("no mins field in lease time"))??;
+    let secs = fields
+        .next()
+        .ok_or(D::Error::custom("no secs field in lease time"))??;
+    let secs_total = days * 86400 + hours * 3600 + mins * 60 + secs;
+    Ok(Duration::from_secs(u64::from(secs_total)))
+}
+
+fn deserialize_uptime<'de, D>(d: D) -> Result<Duration, D::Error>
+where
+    D: Deserializer<'de>,
+{
+    // the uptime is formatted like "12day(s)3h:45m:06s"
+    let s = <&str as Deserialize>::deserialize(d)?;
+    let mut fields = s
+        .split(|c: char| !c.is_ascii_digit())
+        .filter(|f| !f.is_empty())
+        .map(|f| {
+            f.parse::<u64>()
+                .map_err(|e| D::Error::custom(e.to_string()))
+        });
+    let days = fields
+        .next()
+        .ok_or(D::Error::custom("no days field in uptime"))??;
+    let hours = fields
+        .next()
+        .ok_or(D::Error::custom("no hours field in uptime"))??;
+    let mins = fields
+        .next()
+        .ok_or(D::Error::custom("no mins field in uptime"))??;
+    let secs = fields
+        .next()
+        .ok_or(D::Error::custom("no secs field in uptime"))??;
+    Ok(Duration::from_secs(
+        days * 86400 + hours * 3600 + mins * 60 + secs,
+    ))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use serde::de::value::{BorrowedStrDeserializer, Error as ValueError};
+
+    fn uptime(s: &str) -> Result<Duration, ValueError> {
+        deserialize_uptime(BorrowedStrDeserializer::<ValueError>::new(s))
+    }
+
+    #[test]
+    fn parse_flat_downstream_list() {
+        let channel = "<freq>602000000</freq><pow>4</pow><snr>40</snr><RxMER>40.4</RxMER>\
+            <mod>256qam</mod><chid>3</chid><PreRs>12</PreRs><PostRs>0</PostRs>\
+            <IsQamLocked>1</IsQamLocked><IsFECLocked>1</IsFECLocked><IsMpegLocked>1</IsMpegLocked>";
+        let xml = format!(
+            "<downstream_table><ds_num>2</ds_num><downstream>{channel}</downstream>\
+            <downstream>{channel}</downstream></downstream_table>"
+        );
+        let table: DownstreamChannels = quick_xml::de::from_str(&xml).unwrap();
+        assert_eq!(table.channels.len(), 2);
+        assert_eq!(table.channels[0].modulation, Modulation::Qam256);
+
+        let empty: DownstreamChannels =
+            quick_xml::de::from_str("<downstream_table><ds_num>0</ds_num></downstream_table>")
+                .unwrap();
+        assert!(empty.channels.is_empty());
+    }
+
+    #[test]
+    fn ping_statistics() {
+        let status = |rtts: &[Option<u64>]| PingStatus {
+            finished: true,
+            probes: rtts
+                .iter()
+                .map(|rtt| PingProbe {
+                    rtt: rtt.map(Duration::from_millis),
+                })
+                .collect(),
+        };
+
+        let none_sent = PingResult::new(status(&[]), 4);
+        assert_eq!(none_sent.loss_percent, 100.0);
+        assert_eq!(
+            (none_sent.min, none_sent.avg, none_sent.max),
+            (None, None, None)
+        );
+
+        let mixed = PingResult::new(status(&[Some(10), None, Some(30), None]), 4);
+        assert_eq!(mixed.loss_percent, 50.0);
+        assert_eq!(mixed.min, Some(Duration::from_millis(10)));
+        assert_eq!(mixed.avg, Some(Duration::from_millis(20)));
+        assert_eq!(mixed.max, Some(Duration::from_millis(30)));
+
+        // probes that never came back count as lost
+        let partial = PingResult::new(status(&[Some(10), Some(20)]), 4);
+        assert_eq!(partial.loss_percent, 50.0);
+        assert_eq!(partial.probes.len(), 2);
+
+        let clean = PingResult::new(status(&[Some(5); 4]), 4);
+        assert_eq!(clean.loss_percent, 0.0);
+        assert_eq!(clean.avg, Some(Duration::from_millis(5)));
+    }
+
+    #[test]
+    fn parse_optional_lease_time() {
+        let lease = |s| optional_lease_time(BorrowedStrDeserializer::<ValueError>::new(s));
+        assert_eq!(
+            lease("1:02:03:04").unwrap(),
+            Some(Duration::from_secs(86400 + 2 * 3600 + 3 * 60 + 4))
+        );
+        assert_eq!(lease("").unwrap(), None);
+        assert!(lease("1:02").is_err());
+    }
+
+    #[test]
+    fn parse_mac_address() {
+        let mac = MacAddress([0x00, 0x1a, 0x2b, 0x3c, 0x4d, 0xff]);
+        assert_eq!("00:1a:2b:3c:4d:ff".parse::<MacAddress>().unwrap(), mac);
+        assert_eq!("00-1A-2B-3C-4D-FF".parse::<MacAddress>().unwrap(), mac);
+        for s in [
+            "",
+            "00:1a:2b:3c:4d",
+            "00:1a:2b:3c:4d:ff:00",
+            "0:1a:2b:3c:4d:ff",
+            "+a:+b:+c:+d:+e:+f",
+            "00:1a:2b:3c:4d:fg",
+        ] {
+            assert!(s.parse::<MacAddress>().is_err(), "{s}");
+        }
+    }
+
+    #[test]
+    fn parse_uptime() {
+        assert_eq!(
+            uptime("12day(s)3h:45m:06s").unwrap(),
+            Duration::from_secs(12 * 86400 + 3 * 3600 + 45 * 60 + 6)
+        );
+        assert_eq!(uptime("0day(s)0h:00m:00s").unwrap(), Duration::ZERO);
+        assert!(uptime("").is_err());
+        assert!(uptime("3h:45m:06s").is_err());
+        assert!(uptime("12day(s)99999999999999999999h:45m:06s").is_err());
+    }
 }
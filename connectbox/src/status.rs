@@ -0,0 +1,27 @@
+//! Status getters that don't require logging in, shared by [`ConnectBox`](crate::ConnectBox) and [`BridgeModeClient`](crate::BridgeModeClient).
+
+use serde::de::DeserializeOwned;
+
+use crate::{functions, models, Result};
+
+/// A client that can execute getter functions
+pub(crate) trait XmlGetter {
+    async fn xml_getter<T: DeserializeOwned>(&self, function: u32) -> Result<T>;
+}
+
+pub(crate) async fn downstream_channels(
+    client: &impl XmlGetter,
+) -> Result<models::DownstreamChannels> {
+    client.xml_getter(functions::DOWNSTREAM_TABLE).await
+}
+
+pub(crate) async fn upstream_channels(client: &impl XmlGetter) -> Result<models::UpstreamChannels> {
+    client.xml_getter(functions::UPSTREAM_TABLE).await
+}
+
+pub(crate) async fn system_info(client: &impl XmlGetter) -> Result<models::SystemInfo> {
+    let global = client.xml_getter(functions::GLOBAL_SETTINGS).await?;
+    let info = client.xml_getter(functions::CM_SYSTEM_INFO).await?;
+    let status = client.xml_getter(functions::CM_STATUS).await?;
+    Ok(models::SystemInfo::new(global, info, status))
+}
@@ -0,0 +1,386 @@
+use std::net::{Ipv4Addr, Ipv6Addr};
+
+use crate::{
+    models::{
+        AccessSchedule, ChannelWidth, IpFilterEntry, Ipv6AddressMode, Ipv6LanSettings, LanSettings,
+        RadioSettings, WifiBand, WifiMode, WifiSecurity,
+    },
+    Error, Result,
+};
+
+pub(crate) fn ssid(ssid: &str) -> Result<()> {
+    if (1..=32).contains(&ssid.len()) {
+        Ok(())
+    } else {
+        Err(Error::InvalidSsid(ssid.to_string()))
+    }
+}
+
+pub(crate) fn passphrase(security: WifiSecurity, passphrase: &str) -> Result<()> {
+    if security == WifiSecurity::Open {
+        return Ok(());
+    }
+    let valid = match passphrase.len() {
+        8..=63 => passphrase.bytes().all(|b| (b' '..=b'~').contains(&b)),
+        64 => passphrase.bytes().all(|b| b.is_ascii_hexdigit()),
+        _ => false,
+    };
+    if valid {
+        Ok(())
+    } else {
+        Err(Error::InvalidPassphrase)
+    }
+}
+
+/// Check that `security` can be written for a network in `band`, i.e. it's not a mode this library doesn't know.
+pub(crate) fn security(band: WifiBand, security: WifiSecurity) -> Result<()> {
+    if let WifiSecurity::Other(id) = security {
+        return Err(Error::UnsupportedRadioSetting {
+            band,
+            setting: format!("security mode {id}"),
+        });
+    }
+    Ok(())
+}
+
+pub(crate) fn radio(band: WifiBand, radio: &RadioSettings) -> Result<()> {
+    ssid(&radio.ssid)?;
+    security(band, radio.security)?;
+    passphrase(radio.security, &radio.passphrase)?;
+    if let Some(channel) = radio.channel {
+        if !band.channels().contains(&channel) {
+            return Err(Error::InvalidChannel { band, channel });
+        }
+    }
+    let width_supported = band == WifiBand::Band5GHz || radio.channel_width != ChannelWidth::Mhz80;
+    if !width_supported {
+        return Err(Error::UnsupportedRadioSetting {
+            band,
+            setting: radio.channel_width.to_string(),
+        });
+    }
+    let mode_supported = match radio.mode {
+        WifiMode::N => true,
+        WifiMode::Bgn | WifiMode::Gn => band == WifiBand::Band2_4GHz,
+        WifiMode::Anac | WifiMode::Nac | WifiMode::Ac => band == WifiBand::Band5GHz,
+    };
+    if !mode_supported {
+        return Err(Error::UnsupportedRadioSetting {
+            band,
+            setting: radio.mode.to_string(),
+        });
+    }
+    Ok(())
+}
+
+pub(crate) fn wps_pin(pin: &str) -> Result<()> {
+    let digits: Vec<u32> = pin.chars().filter_map(|c| c.to_digit(10)).collect();
+    let valid = match (pin.len(), digits.len()) {
+        (4, 4) => true,
+        (8, 8) => {
+            // the last digit is a checksum over the first seven, with weights alternating between 3 and 1
+            let sum: u32 = digits
+                .iter()
+                .enumerate()
+                .map(|(i, d)| if i % 2 == 0 { d * 3 } else { *d })
+                .sum();
+            sum.is_multiple_of(10)
+        }
+        _ => false,
+    };
+    if valid {
+        Ok(())
+    } else {
+        Err(Error::InvalidWpsPin(pin.to_string()))
+    }
+}
+
+pub(crate) fn subnet_mask(mask: Ipv4Addr) -> Result<()> {
+    let bits = u32::from(mask);
+    // the mask must consist of contiguous ones, be at least a /8 and leave room for at least two hosts
+    let contiguous = bits.leading_ones() + bits.trailing_zeros() == 32;
+    if contiguous && bits.leading_ones() >= 8 && bits.trailing_zeros() >= 2 {
+        Ok(())
+    } else {
+        Err(Error::InvalidSubnetMask(mask))
+    }
+}
+
+/// Check that `addr` is a host address (not the network or broadcast address) in the subnet of `lan_ip`.
+pub(crate) fn in_subnet(addr: Ipv4Addr, lan_ip: Ipv4Addr, mask: Ipv4Addr) -> Result<()> {
+    let (addr_bits, mask_bits) = (u32::from(addr), u32::from(mask));
+    let same_network = addr_bits & mask_bits == u32::from(lan_ip) & mask_bits;
+    let host = addr_bits & !mask_bits;
+    if same_network && host != 0 && host != !mask_bits {
+        Ok(())
+    } else {
+        Err(Error::AddressOutsideSubnet(addr))
+    }
+}
+
+/// Check that `addr` can be assigned to a device in the LAN, i.e. it's in the subnet and it's not the router's address.
+pub(crate) fn lan_host(addr: Ipv4Addr, lan_ip: Ipv4Addr, mask: Ipv4Addr) -> Result<()> {
+    in_subnet(addr, lan_ip, mask)?;
+    if addr == lan_ip {
+        return Err(Error::AddressOutsideSubnet(addr));
+    }
+    Ok(())
+}
+
+pub(crate) fn lan_settings(settings: &LanSettings) -> Result<()> {
+    let (lan_ip, mask) = (settings.lan_ip, settings.subnet_mask);
+    subnet_mask(mask)?;
+    in_subnet(lan_ip, lan_ip, mask)?;
+    in_subnet(settings.pool_start, lan_ip, mask)?;
+    in_subnet(settings.pool_end, lan_ip, mask)?;
+    let pool = settings.pool_start..=settings.pool_end;
+    if pool.is_empty() || pool.contains(&lan_ip) {
+        return Err(Error::InvalidDhcpPool(
+            settings.pool_start,
+            settings.pool_end,
+        ));
+    }
+    Ok(())
+}
+
+pub(crate) fn ipv6_lan_settings(settings: &Ipv6LanSettings) -> Result<()> {
+    if settings.address_mode == Ipv6AddressMode::StatefulDhcpv6 && settings.lease_time.is_zero() {
+        return Err(Error::InvalidIpv6LanSettings(
+            "the DHCPv6 lease time must not be zero",
+        ));
+    }
+    let usable = |dns: &Ipv6Addr| !dns.is_unspecified() && !dns.is_multicast();
+    if !settings.dns_servers.iter().all(usable) {
+        return Err(Error::InvalidIpv6LanSettings(
+            "DNS servers must be unicast addresses",
+        ));
+    }
+    Ok(())
+}
+
+pub(crate) fn ip_filter(filter: &IpFilterEntry, lan_ip: Ipv4Addr, mask: Ipv4Addr) -> Result<()> {
+    lan_host(filter.start_ip, lan_ip, mask)?;
+    lan_host(filter.end_ip, lan_ip, mask)?;
+    if filter.start_ip > filter.end_ip {
+        return Err(Error::InvalidAddressRange(filter.start_ip, filter.end_ip));
+    }
+    if filter.start_port > filter.end_port {
+        return Err(Error::InvalidPortRange(filter.start_port, filter.end_port));
+    }
+    Ok(())
+}
+
+pub(crate) fn schedule(schedule: &AccessSchedule) -> Result<()> {
+    let range = &schedule.blocked;
+    for time in [range.start, range.end] {
+        if time.hour > 23 || time.minute > 59 {
+            return Err(Error::InvalidSchedule("time of day out of range"));
+        }
+    }
+    // an end before the start wraps past midnight, so only an empty range is invalid
+    if range.start == range.end {
+        return Err(Error::InvalidSchedule(
+            "the time range must not start and end at the same time",
+        ));
+    }
+    if schedule.days.is_empty() {
+        return Err(Error::InvalidSchedule("no days selected"));
+    }
+    Ok(())
+}
+
+pub(crate) fn keyword(keyword: &str) -> Result<()> {
+    // '*' separates list entries in requests, so it can't be part of a keyword
+    if keyword.trim().is_empty() || keyword.contains('*') {
+        Err(Error::InvalidKeyword(keyword.to_string()))
+    } else {
+        Ok(())
+    }
+}
+
+pub(crate) fn diagnostic_target(target: &str) -> Result<()> {
+    // a leading '-' could be taken as an option by the router's ping and traceroute tools
+    let valid = !target.is_empty()
+        && !target.starts_with('-')
+        && target.len() <= 253
+        && target
+            .chars()
+            .all(|c| c.is_ascii_alphanumeric() || matches!(c, '.' | '-' | ':'));
+    if valid {
+        Ok(())
+    } else {
+        Err(Error::InvalidDiagnosticTarget(target.to_string()))
+    }
+}
+
+pub(crate) fn ping_count(count: u8) -> Result<()> {
+    if count == 0 {
+        Err(Error::InvalidPingCount)
+    } else {
+        Ok(())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::models::{MacAddress, PortForwardProtocol, TimeOfDay, TimeRange, Weekdays};
+
+    #[test]
+    fn wps_pin_checksum() {
+        for pin in ["1234", "12345670", "00000000", "87654325"] {
+            assert!(wps_pin(pin).is_ok(), "{pin}");
+        }
+        for pin in [
+            "",
+            "123",
+            "12345",
+            "12345678",
+            "1234567",
+            "1234567a",
+            "+1234567",
+            "１２３４",
+        ] {
+            assert!(wps_pin(pin).is_err(), "{pin}");
+        }
+    }
+
+    #[test]
+    fn diagnostic_targets() {
+        for target in ["example.com", "192.168.0.1", "2001:db8::1", "my-host"] {
+            assert!(diagnostic_target(target).is_ok(), "{target}");
+        }
+        for target in ["", "-f", "-c100", "--help", "a b", "host;reboot"] {
+            assert!(diagnostic_target(target).is_err(), "{target}");
+        }
+        // traceroute shares the check, so its options must be rejected as well
+        for target in ["-I", "-m255", "-6"] {
+            assert!(diagnostic_target(target).is_err(), "{target}");
+        }
+    }
+
+    #[test]
+    fn subnet_masks() {
+        for mask in [
+            "255.255.255.0",
+            "255.255.0.0",
+            "255.0.0.0",
+            "255.255.255.252",
+        ] {
+            assert!(subnet_mask(mask.parse().unwrap()).is_ok(), "{mask}");
+        }
+        for mask in [
+            "0.0.0.0",
+            "255.255.255.254",
+            "255.255.255.255",
+            "255.0.255.0",
+            "255.255.255.1",
+        ] {
+            assert!(subnet_mask(mask.parse().unwrap()).is_err(), "{mask}");
+        }
+    }
+
+    #[test]
+    fn subnet_membership() {
+        let lan_ip = Ipv4Addr::new(192, 168, 0, 1);
+        let mask = Ipv4Addr::new(255, 255, 255, 0);
+        assert!(in_subnet(Ipv4Addr::new(192, 168, 0, 10), lan_ip, mask).is_ok());
+        assert!(in_subnet(lan_ip, lan_ip, mask).is_ok());
+        for addr in [
+            Ipv4Addr::new(192, 168, 0, 0),
+            Ipv4Addr::new(192, 168, 0, 255),
+            Ipv4Addr::new(192, 168, 1, 10),
+        ] {
+            assert!(in_subnet(addr, lan_ip, mask).is_err(), "{addr}");
+        }
+        assert!(lan_host(Ipv4Addr::new(192, 168, 0, 10), lan_ip, mask).is_ok());
+        assert!(lan_host(lan_ip, lan_ip, mask).is_err());
+    }
+
+    #[test]
+    fn dhcp_pool() {
+        let mut settings = LanSettings {
+            lan_ip: Ipv4Addr::new(192, 168, 0, 1),
+            subnet_mask: Ipv4Addr::new(255, 255, 255, 0),
+            dhcp_enabled: true,
+            pool_start: Ipv4Addr::new(192, 168, 0, 10),
+            pool_end: Ipv4Addr::new(192, 168, 0, 200),
+            lease_time: std::time::Duration::from_secs(86400),
+            dns_servers: Vec::new(),
+        };
+        assert!(lan_settings(&settings).is_ok());
+        settings.pool_start = Ipv4Addr::new(192, 168, 0, 201);
+        assert!(lan_settings(&settings).is_err());
+        settings.pool_start = Ipv4Addr::new(192, 168, 0, 1);
+        assert!(lan_settings(&settings).is_err());
+    }
+
+    #[test]
+    fn ipv6_lease_time() {
+        let mut settings = Ipv6LanSettings {
+            prefix: None,
+            address_mode: Ipv6AddressMode::StatefulDhcpv6,
+            lease_time: std::time::Duration::from_secs(3600),
+            dns_servers: vec!["2001:4860:4860::8888".parse().unwrap()],
+        };
+        assert!(ipv6_lan_settings(&settings).is_ok());
+        settings.lease_time = std::time::Duration::ZERO;
+        assert!(ipv6_lan_settings(&settings).is_err());
+        settings.address_mode = Ipv6AddressMode::Slaac;
+        assert!(ipv6_lan_settings(&settings).is_ok());
+        settings.dns_servers = vec![Ipv6Addr::UNSPECIFIED];
+        assert!(ipv6_lan_settings(&settings).is_err());
+    }
+
+    #[test]
+    fn ip_filter_ranges() {
+        let lan_ip = Ipv4Addr::new(192, 168, 0, 1);
+        let mask = Ipv4Addr::new(255, 255, 255, 0);
+        let mut filter = IpFilterEntry {
+            id: 0,
+            start_ip: Ipv4Addr::new(192, 168, 0, 10),
+            end_ip: Ipv4Addr::new(192, 168, 0, 20),
+            start_port: 80,
+            end_port: 443,
+            protocol: PortForwardProtocol::Tcp,
+            enable: true,
+        };
+        assert!(ip_filter(&filter, lan_ip, mask).is_ok());
+        filter.end_ip = Ipv4Addr::new(192, 168, 0, 5);
+        assert!(ip_filter(&filter, lan_ip, mask).is_err());
+        filter.end_ip = Ipv4Addr::new(192, 168, 0, 20);
+        filter.end_port = 79;
+        assert!(ip_filter(&filter, lan_ip, mask).is_err());
+    }
+
+    #[test]
+    fn schedule_ranges() {
+        let schedule = |start: (u8, u8), end: (u8, u8)| AccessSchedule {
+            id: 0,
+            name: "test".to_string(),
+            mac: MacAddress([0; 6]),
+            days: Weekdays::WORKDAYS,
+            blocked: TimeRange {
+                start: TimeOfDay {
+                    hour: start.0,
+                    minute: start.1,
+                },
+                end: TimeOfDay {
+                    hour: end.0,
+                    minute: end.1,
+                },
+            },
+            enable: true,
+        };
+        assert!(super::schedule(&schedule((8, 0), (17, 30))).is_ok());
+        // overnight ranges wrap past midnight
+        assert!(super::schedule(&schedule((22, 0), (7, 0))).is_ok());
+        assert!(super::schedule(&schedule((22, 0), (0, 0))).is_ok());
+        assert!(super::schedule(&schedule((22, 0), (22, 0))).is_err());
+        assert!(super::schedule(&schedule((24, 0), (7, 0))).is_err());
+        assert!(super::schedule(&schedule((22, 60), (7, 0))).is_err());
+        let mut no_days = schedule((22, 0), (7, 0));
+        no_days.days = Weekdays::default();
+        assert!(super::schedule(&no_days).is_err());
+    }
+}